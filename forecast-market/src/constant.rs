@@ -1,5 +1,10 @@
 pub const BASIS_POINTS: u16 = 10_000;
 pub const MAX_PERCENTAGE_BASIS_POINTS: u128 = 100_000_000;
+pub const ODDS_BASIS_POINTS: u64 = 10_000; // decimal odds of 1.0
 pub const SECONDS_IN_A_YEAR: u64 = 31_536_000; // 365 days * 24 hours * 60 minutes * 60 seconds
 pub const SUCCESS_MARKET_VALIDITY_DATE : u64 =  15_552_000; // 180 days * 24 hours * 60 minutes * 60 seconds
 pub const ADJOURN_MARKET_VALIDITY_DATE : u64 =  15_552_000; // 180 days * 24 hours * 60 minutes * 60 seconds
+pub const CRANK_CLAIM_DELAY : u64 = 604_800; // 7 days * 24 hours * 60 minutes * 60 seconds
+pub const CRANK_FEE_BASIS_POINTS: u64 = 10; // 0.1% of the payout
+pub const REWARD_STAKE_LOCK_PERIOD : u64 = 604_800; // 7 days * 24 hours * 60 minutes * 60 seconds
+pub const MIN_CONFIG_CHANGE_DELAY : u64 = 86_400; // 24 hours * 60 minutes * 60 seconds
@@ -44,4 +44,124 @@ pub enum ProgramErrorCode {
     InvalidForeignEmitter,
     #[msg("Invalid message")]
     InvalidMessage,
+    #[msg("Market: Operation is not supported for this market type")]
+    InvalidMarketType,
+    #[msg("Market/FundLmsrMarket: Market is not a draft")]
+    MarketNotDraft,
+    #[msg("Market/FundLmsrMarket: Invalid liquidity parameter")]
+    InvalidLiquidityParam,
+    #[msg("Market/FundLmsrMarket: Market needs at least two answers")]
+    NotEnoughAnswers,
+    #[msg("Market/AddAnswer: Answers are locked once the market is funded")]
+    AnswersLocked,
+    #[msg("Market/Trade: Price moved beyond the accepted limit")]
+    SlippageExceeded,
+    #[msg("Market/SellShares: Not enough shares")]
+    InsufficientShares,
+    #[msg("Market/OutcomeToken: Outcome mint and token accounts do not match the market answers")]
+    InvalidOutcomeAccounts,
+    #[msg("Market/OutcomeToken: Outcome token has no redeemable value")]
+    NothingToRedeem,
+    #[msg("Market/SetAnswerOdds: Odds must be above 1.0")]
+    InvalidOdds,
+    #[msg("Market/Bet: No odds published for this answer")]
+    OddsNotSet,
+    #[msg("Market/Bet: Bet exceeds the house liquidity")]
+    LiabilityExceedsHouse,
+    #[msg("Market/BetWithIntent: Missing or invalid ed25519 signature")]
+    InvalidSignature,
+    #[msg("Market/BetWithIntent: Intent has expired")]
+    IntentExpired,
+    #[msg("Market/BetWithIntent: Invalid intent nonce")]
+    InvalidNonce,
+    #[msg("Session: Signer is not the session key")]
+    InvalidSession,
+    #[msg("Session: Session has expired")]
+    SessionExpired,
+    #[msg("Session: Action is outside the session scope")]
+    SessionScopeExceeded,
+    #[msg("Market/CrankClaim: Claim cannot be cranked yet")]
+    CrankTooEarly,
+    #[msg("Market/ClaimCrossChain: Cross-chain bet already settled")]
+    AlreadySettled,
+    #[msg("Market/Reward: Invalid reward policy")]
+    InvalidRewardPolicy,
+    #[msg("Market/Vesting: Vesting account is required for this claim")]
+    VestingAccountRequired,
+    #[msg("Market/Vesting: Vesting markets must be claimed with claim_token")]
+    VestingNotSupported,
+    #[msg("Market/Vesting: Nothing to withdraw yet")]
+    NothingToWithdraw,
+    #[msg("Market/Fee: Fee percentages exceed the fee cap")]
+    InvalidFeePercentage,
+    #[msg("Market/Fee: Too many creator fee overrides")]
+    TooManyFeeOverrides,
+    #[msg("Market/Fee: Invalid revenue split")]
+    InvalidRevenueSplit,
+    #[msg("Market/Fee: Fee ledger does not match the revenue split")]
+    InvalidFeeLedger,
+    #[msg("Market/Fee: Invalid fee discount tiers")]
+    InvalidFeeDiscount,
+    #[msg("Roles: Too many role grants")]
+    TooManyRoleGrants,
+    #[msg("Config: Ownership transfer delay has not passed")]
+    OwnerTransferTooEarly,
+    #[msg("Config: Config change delay has not passed")]
+    ConfigChangeTooEarly,
+    #[msg("Resolution: Invalid resolver committee")]
+    InvalidCommittee,
+    #[msg("Resolution: Signer is not a committee member")]
+    NotCommitteeMember,
+    #[msg("Resolution: Member already attested")]
+    AlreadyAttested,
+    #[msg("Resolution: Committee has not agreed on this answer")]
+    ResolutionNotAgreed,
+    #[msg("Oracle: Invalid oracle config")]
+    InvalidOracleConfig,
+    #[msg("Oracle: Proposal is not in the expected state")]
+    InvalidProposalStatus,
+    #[msg("Oracle: Liveness window has not ended")]
+    LivenessNotEnded,
+    #[msg("Oracle: Liveness window has ended")]
+    LivenessEnded,
+    #[msg("Governance: Market is not disputed")]
+    MarketNotDisputed,
+    #[msg("Governance: Vote amount must be positive")]
+    InvalidVoteAmount,
+    #[msg("Governance: Voting window is closed")]
+    VotingClosed,
+    #[msg("Governance: Voting window has not ended")]
+    VotingNotEnded,
+    #[msg("Governance: No answer leads the vote")]
+    NoVoteLeader,
+    #[msg("Governance: Vote must stay on the same answer")]
+    VoteAnswerMismatch,
+    #[msg("Migration: Account is already on the current version")]
+    AlreadyMigrated,
+    #[msg("Market/DraftMarket: Title is too long")]
+    TitleTooLong,
+    #[msg("Oracle: Market resolves through its committee")]
+    CommitteeMarket,
+    #[msg("Oracle: Bond must be positive")]
+    InvalidBondAmount,
+    #[msg("Oracle: Market has an open resolution proposal")]
+    ProposalPending,
+    #[msg("Market/FundLmsrMarket: Too many answers for an LMSR market")]
+    TooManyLmsrOutcomes,
+    #[msg("Market: Mints with a transfer fee are not supported")]
+    TransferFeeNotSupported,
+    #[msg("Market/Reward: Wrong reward vault for this market")]
+    InvalidRewardVault,
+    #[msg("Stake: Stake amount must be positive")]
+    InvalidStakeAmount,
+    #[msg("Stake: Stake is still locked")]
+    StakeLocked,
+    #[msg("Stake: Not enough tokens staked")]
+    InsufficientStake,
+    #[msg("Market/Vesting: Nothing in this claim vests")]
+    VestingAccountNotNeeded,
+    #[msg("Market/Retrieve: Vault does not cover the reserved balances")]
+    ReservedTokensShortfall,
+    #[msg("Config: Config change delay is below the minimum")]
+    ConfigDelayTooShort,
 }
@@ -1,11 +1,18 @@
 use crate::{
     constant::{
-        ADJOURN_MARKET_VALIDITY_DATE, BASIS_POINTS, SECONDS_IN_A_YEAR, SUCCESS_MARKET_VALIDITY_DATE,
+        ADJOURN_MARKET_VALIDITY_DATE, BASIS_POINTS, MAX_PERCENTAGE_BASIS_POINTS, ODDS_BASIS_POINTS,
+        SECONDS_IN_A_YEAR, SUCCESS_MARKET_VALIDITY_DATE,
     },
     error::ProgramErrorCode,
-    MarketAccount, MarketStatus,
+    AnswerAccount, BettingAccount, ConfigAccount, MarketAccount, MarketStatus, MarketType,
+    RewardPolicy, CONFIG_SEED, MARKET_REWARD_SEED, MARKET_SEED,
 };
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
 use anchor_spl::{
     token::{self, Token},
     token_2022::{
@@ -123,6 +130,165 @@ pub fn transfer_from_pool_vault_to_user<'info>(
 }
 
 
+pub fn approve_delegate<'info>(
+    token_account: &AccountInfo<'info>,
+    delegate: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    token_program_2022: Option<&AccountInfo<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let mut token_program_info = token_program.to_account_info();
+    let token_account_info = token_account.to_account_info();
+
+    if let Some(token_program_2022) = token_program_2022 {
+        if token_account_info.owner == token_program_2022.key {
+            token_program_info = token_program_2022.to_account_info();
+        }
+    }
+
+    token_2022::approve(
+        CpiContext::new(
+            token_program_info,
+            token_2022::Approve {
+                to: token_account_info,
+                delegate: delegate.to_account_info(),
+                authority: authority.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+pub fn revoke_delegate<'info>(
+    token_account: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    token_program_2022: Option<&AccountInfo<'info>>,
+) -> Result<()> {
+    let mut token_program_info = token_program.to_account_info();
+    let token_account_info = token_account.to_account_info();
+
+    if let Some(token_program_2022) = token_program_2022 {
+        if token_account_info.owner == token_program_2022.key {
+            token_program_info = token_program_2022.to_account_info();
+        }
+    }
+
+    token_2022::revoke(CpiContext::new(
+        token_program_info,
+        token_2022::Revoke {
+            source: token_account_info,
+            authority: authority.to_account_info(),
+        },
+    ))
+}
+
+/// Rewrite a migrated account in its current layout. The account grows to
+/// fit it, and to at least `min_space`, with `payer` topping up the rent; it
+/// is never shrunk.
+pub fn write_migrated_account<'info, T: AccountSerialize>(
+    account_info: &AccountInfo<'info>,
+    account: &T,
+    min_space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let mut serialized = Vec::new();
+    account.try_serialize(&mut serialized)?;
+
+    let space = serialized.len().max(min_space);
+
+    if space > account_info.data_len() {
+        let rent = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(account_info.lamports());
+
+        if rent > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: payer.to_account_info(),
+                        to: account_info.to_account_info(),
+                    },
+                ),
+                rent,
+            )?;
+        }
+
+        account_info.realloc(space, false)?;
+    }
+
+    let mut data = account_info.try_borrow_mut_data()?;
+    data.fill(0);
+    data[..serialized.len()].copy_from_slice(&serialized);
+
+    Ok(())
+}
+
+/// Pay `amount` of a market's reward from `vault`, which has to be the
+/// market's reward escrow, or the config reward vault for a market without one.
+pub fn transfer_market_reward<'info>(
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    to: &AccountInfo<'info>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
+    market_account: &Account<'info, MarketAccount>,
+    config_account: &Account<'info, ConfigAccount>,
+    token_program: &AccountInfo<'info>,
+    token_program_2022: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        vault.mint == mint.key(),
+        ProgramErrorCode::InvalidRewardVault
+    );
+
+    if market_account.has_reward_escrow() {
+        let (escrow, _) = Pubkey::find_program_address(
+            &[
+                MARKET_REWARD_SEED.as_bytes(),
+                &market_account.market_key.to_le_bytes(),
+            ],
+            &crate::ID,
+        );
+        require!(vault.key() == escrow, ProgramErrorCode::InvalidRewardVault);
+
+        let seeds: &[&[u8]] = &[
+            MARKET_SEED.as_bytes(),
+            &market_account.market_key.to_le_bytes(),
+            &[market_account.bump],
+        ];
+        transfer_from_pool_vault_to_user(
+            &vault.to_account_info(),
+            to,
+            mint,
+            &market_account.to_account_info(),
+            token_program,
+            Some(token_program_2022),
+            amount,
+            &[&seeds],
+        )
+    } else {
+        require!(
+            vault.owner == config_account.key(),
+            ProgramErrorCode::InvalidRewardVault
+        );
+
+        let seeds: &[&[u8]] = &[CONFIG_SEED.as_bytes(), &[config_account.bump]];
+        transfer_from_pool_vault_to_user(
+            &vault.to_account_info(),
+            to,
+            mint,
+            &config_account.to_account_info(),
+            token_program,
+            Some(token_program_2022),
+            amount,
+            &[&seeds],
+        )
+    }
+}
+
 pub fn close_spl_account<'a, 'b, 'c, 'info>(
     owner: &AccountInfo<'info>,
     destination: &AccountInfo<'info>,
@@ -170,6 +336,17 @@ pub fn get_transfer_fee(
     Ok(fee)
 }
 
+/// Whether transfers of the mint can be charged a Token-2022 transfer fee.
+pub fn has_transfer_fee(mint_account: &InterfaceAccount<Mint>) -> Result<bool> {
+    let mint_info = mint_account.to_account_info();
+    if *mint_info.owner == Token::id() {
+        return Ok(false);
+    }
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    Ok(mint.get_extension::<TransferFeeConfig>().is_ok())
+}
+
 /// Calculate the fee for output amount
 pub fn get_transfer_inverse_fee(
     mint_account: Box<InterfaceAccount<Mint>>,
@@ -232,6 +409,359 @@ pub fn calculate_reward_amount(
     Ok(reward_amount)
 }
 
+/// Multiplier in basis points for an `EarlyBird` bet, decaying linearly from
+/// `max_multiplier` at approval to `BASIS_POINTS` at finish.
+pub fn calculate_early_bird_multiplier(
+    max_multiplier: u64,
+    approve_time: u64,
+    create_time: u64,
+    finish_time: u64,
+) -> Result<u64> {
+    require!(
+        approve_time <= create_time && create_time <= finish_time,
+        ProgramErrorCode::InvalidTimeRange
+    );
+
+    let open_period = finish_time - approve_time;
+    if open_period == 0 {
+        return Ok(max_multiplier);
+    }
+
+    let bonus = (max_multiplier.saturating_sub(BASIS_POINTS as u64) as u128)
+        .checked_mul((finish_time - create_time) as u128)
+        .and_then(|r| r.checked_div(open_period as u128))
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    Ok(BASIS_POINTS as u64 + bonus as u64)
+}
+
+/// Reward owed to a betting account under the market's `RewardPolicy`,
+/// before scaling to the reward budget.
+pub fn calculate_policy_reward(
+    market_account: &MarketAccount,
+    betting_account: &BettingAccount,
+    receive_tokens: u64,
+) -> Result<u64> {
+    let losing_tokens = if receive_tokens > 0 {
+        0
+    } else {
+        betting_account.tokens
+    };
+
+    match market_account.reward_policy {
+        RewardPolicy::Linear => calculate_reward_amount(
+            losing_tokens,
+            market_account.reward_apr,
+            betting_account.create_time,
+            market_account.finish_time,
+        ),
+        RewardPolicy::EarlyBird { max_multiplier } => {
+            let reward_amount = calculate_reward_amount(
+                losing_tokens,
+                market_account.reward_apr,
+                betting_account.create_time,
+                market_account.finish_time,
+            )?;
+            let multiplier = calculate_early_bird_multiplier(
+                max_multiplier,
+                market_account.approve_time,
+                betting_account.create_time,
+                market_account.finish_time,
+            )?;
+
+            let reward_amount = (reward_amount as u128)
+                .checked_mul(multiplier as u128)
+                .and_then(|r| r.checked_div(BASIS_POINTS as u128))
+                .ok_or(ProgramErrorCode::Overflow)?;
+            Ok(u64::try_from(reward_amount).map_err(|_| ProgramErrorCode::Overflow)?)
+        }
+        RewardPolicy::Participation { amount } => Ok(amount),
+        RewardPolicy::Consolation { basis_points } => {
+            let reward_amount = (losing_tokens as u128)
+                .checked_mul(basis_points as u128)
+                .and_then(|r| r.checked_div(BASIS_POINTS as u128))
+                .ok_or(ProgramErrorCode::Overflow)?;
+            Ok(reward_amount as u64)
+        }
+    }
+}
+
+/// Tokens staked on answers that did not win. Only these earn the loss based
+/// rewards; an adjourned market refunds every bet.
+fn calculate_losing_tokens(
+    market_account: &MarketAccount,
+    answer_account: &AnswerAccount,
+) -> Result<u64> {
+    if market_account.status != MarketStatus::Success {
+        return Ok(0);
+    }
+
+    let winning_tokens = answer_account
+        .answers
+        .iter()
+        .find(|answer| answer.answer_key == market_account.correct_answer_key)
+        .map(|answer| answer.answer_total_tokens)
+        .unwrap_or(0);
+
+    market_account
+        .market_total_tokens
+        .checked_sub(winning_tokens)
+        .ok_or(ProgramErrorCode::MathOperationError.into())
+}
+
+/// Upper bound of the rewards a resolved market can owe under its
+/// `RewardPolicy`, counting only the bets that can earn one.
+pub fn calculate_reward_liability(
+    market_account: &MarketAccount,
+    answer_account: &AnswerAccount,
+) -> Result<u64> {
+    let losing_tokens = calculate_losing_tokens(market_account, answer_account)?;
+
+    let liability = match market_account.reward_policy {
+        RewardPolicy::Linear => calculate_apr_liability(market_account, losing_tokens)? as u128,
+        RewardPolicy::EarlyBird { max_multiplier } => {
+            (calculate_apr_liability(market_account, losing_tokens)? as u128)
+                .checked_mul(max_multiplier as u128)
+                .and_then(|r| r.checked_div(BASIS_POINTS as u128))
+                .ok_or(ProgramErrorCode::Overflow)?
+        }
+        RewardPolicy::Participation { amount } => (amount as u128)
+            .checked_mul(market_account.reward_bet_count as u128)
+            .ok_or(ProgramErrorCode::Overflow)?,
+        RewardPolicy::Consolation { basis_points } => (losing_tokens as u128)
+            .checked_mul(basis_points as u128)
+            .and_then(|r| r.checked_div(BASIS_POINTS as u128))
+            .ok_or(ProgramErrorCode::Overflow)?,
+    };
+
+    Ok(u64::try_from(liability).map_err(|_| ProgramErrorCode::Overflow)?)
+}
+
+/// APR rewards owed to `losing_tokens`. `reward_stake_time` accumulates
+/// `tokens * create_time` over all bets, which caps the losing bets' staked
+/// time; so does every losing bet having been placed after approval.
+fn calculate_apr_liability(market_account: &MarketAccount, losing_tokens: u64) -> Result<u64> {
+    let staked_seconds = (market_account.market_total_tokens as u128)
+        .checked_mul(market_account.finish_time as u128)
+        .and_then(|r| r.checked_sub(market_account.reward_stake_time))
+        .ok_or(ProgramErrorCode::InvalidTimeRange)?;
+    let losing_staked_seconds = (losing_tokens as u128)
+        .checked_mul(
+            market_account
+                .finish_time
+                .saturating_sub(market_account.approve_time) as u128,
+        )
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let liability = staked_seconds
+        .min(losing_staked_seconds)
+        .checked_mul(market_account.reward_apr as u128)
+        .and_then(|r| r.checked_div(BASIS_POINTS as u128))
+        .and_then(|r| r.checked_div(SECONDS_IN_A_YEAR as u128))
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    Ok(u64::try_from(liability).map_err(|_| ProgramErrorCode::Overflow)?)
+}
+
+/// Scales a reward down pro rata when the market's reward liability exceeds
+/// its budget, and never pays beyond what is left of the budget.
+pub fn scale_reward_to_budget(
+    market_account: &MarketAccount,
+    answer_account: &AnswerAccount,
+    reward_amount: u64,
+) -> Result<u64> {
+    if reward_amount == 0 {
+        return Ok(0);
+    }
+
+    let liability = calculate_reward_liability(market_account, answer_account)?;
+    let scaled_amount = if liability > market_account.reward_budget {
+        (reward_amount as u128)
+            .checked_mul(market_account.reward_budget as u128)
+            .and_then(|r| r.checked_div(liability as u128))
+            .ok_or(ProgramErrorCode::Overflow)? as u64
+    } else {
+        reward_amount
+    };
+
+    let remain_budget = market_account
+        .reward_budget
+        .saturating_sub(market_account.reward_paid);
+
+    Ok(scaled_amount.min(remain_budget))
+}
+
+pub struct MarketFees {
+    pub creator_fee: u64,
+    pub service_fee: u64,
+    pub reward_base_tokens: u64,
+}
+
+/// Split the tokens remaining in a market into creator fee, service fee and
+/// the base that is shared between the winning bets.
+pub fn calculate_market_fees(market_account: &MarketAccount, remain_tokens: u64) -> Result<MarketFees> {
+    let remain_tokens = remain_tokens as u128;
+
+    let creator_fee_percentage = market_account.creator_fee_percentage as u128;
+    let service_fee_percentage = market_account.service_fee_percentage as u128;
+
+    let additional_creator_fee = remain_tokens
+        .checked_mul(creator_fee_percentage)
+        .and_then(|result| result.checked_div(BASIS_POINTS as u128))
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    let service_fee = remain_tokens
+        .checked_mul(service_fee_percentage)
+        .and_then(|result| result.checked_div(BASIS_POINTS as u128))
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    // The flat fee is set at draft time, before the pool size is known, so it
+    // is capped at what the percentage fees leave rather than failing
+    // resolution.
+    let old_creator_fee = (market_account.creator_fee as u128).min(
+        remain_tokens
+            .saturating_sub(additional_creator_fee)
+            .saturating_sub(service_fee),
+    );
+
+    let creator_fee = old_creator_fee
+        .checked_add(additional_creator_fee)
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    let reward_base_tokens = remain_tokens
+        .checked_sub(creator_fee)
+        .and_then(|result| result.checked_sub(service_fee))
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    Ok(MarketFees {
+        creator_fee: creator_fee as u64,
+        service_fee: service_fee as u64,
+        reward_base_tokens: reward_base_tokens as u64,
+    })
+}
+
+/// Share of the pool, in `MAX_PERCENTAGE_BASIS_POINTS`, paid out per token of a
+/// bet on `answer_key`.
+pub fn calculate_claim_percentage(
+    market_account: &MarketAccount,
+    answer_account: &AnswerAccount,
+    answer_key: u64,
+) -> Result<u128> {
+    let correct_answer_key = market_account.correct_answer_key;
+    let mut percentage = 0;
+
+    if market_account.status == MarketStatus::Success && answer_key == correct_answer_key {
+        let mut correct_answer_total_tokens: u128 = 0;
+        for answer in &answer_account.answers {
+            if answer.answer_key == correct_answer_key {
+                correct_answer_total_tokens = answer.answer_total_tokens as u128;
+                break;
+            }
+        }
+
+        let market_reward_base_tokens = market_account.market_reward_base_tokens as u128;
+        percentage = market_reward_base_tokens
+            .checked_mul(MAX_PERCENTAGE_BASIS_POINTS)
+            .and_then(|result| result.checked_div(correct_answer_total_tokens))
+            .ok_or(ProgramErrorCode::MathOperationError)?;
+    } else if market_account.status == MarketStatus::Adjourn {
+        percentage = MAX_PERCENTAGE_BASIS_POINTS;
+        let answer_exists = answer_account
+            .answers
+            .iter()
+            .any(|answer| answer.answer_key == answer_key);
+
+        if !answer_exists {
+            return Err(ProgramErrorCode::InvalidAnswerKey.into());
+        }
+    }
+
+    Ok(percentage)
+}
+
+pub fn calculate_receive_tokens(betting_tokens: u64, percentage: u128) -> Result<u64> {
+    let receive_tokens = (betting_tokens as u128)
+        .checked_mul(percentage)
+        .and_then(|result| result.checked_div(MAX_PERCENTAGE_BASIS_POINTS))
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    Ok(receive_tokens as u64)
+}
+
+pub struct ClaimAmounts {
+    pub receive_tokens: u64,
+    pub service_fee: u64,
+    pub reward_amount: u64,
+}
+
+/// Service fee taken from a winning claim, less the staker's discount.
+pub fn calculate_claim_service_fee(
+    gross_tokens: u64,
+    service_fee_percentage: u64,
+    discount_bps: u16,
+) -> Result<u64> {
+    let service_fee = (gross_tokens as u128)
+        .checked_mul(service_fee_percentage as u128)
+        .and_then(|r| r.checked_mul(BASIS_POINTS.saturating_sub(discount_bps) as u128))
+        .and_then(|r| r.checked_div(BASIS_POINTS as u128 * BASIS_POINTS as u128))
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    Ok(service_fee as u64)
+}
+
+/// Amounts `claim_token` pays out for a betting account. Winnings pay the
+/// market's claim service fee less `discount_bps`. The reward follows the
+/// market's `RewardPolicy` and is scaled to the market's budget.
+pub fn calculate_claim_amounts(
+    market_account: &MarketAccount,
+    answer_account: &AnswerAccount,
+    betting_account: &BettingAccount,
+    discount_bps: u16,
+) -> Result<ClaimAmounts> {
+    let receive_tokens = if market_account.market_type == MarketType::FixedOdds
+        && market_account.status == MarketStatus::Success
+    {
+        if betting_account.answer_key == market_account.correct_answer_key {
+            betting_account.locked_payout
+        } else {
+            0
+        }
+    } else {
+        let percentage =
+            calculate_claim_percentage(market_account, answer_account, betting_account.answer_key)?;
+        calculate_receive_tokens(betting_account.tokens, percentage)?
+    };
+
+    let service_fee = if market_account.status == MarketStatus::Success {
+        calculate_claim_service_fee(
+            receive_tokens,
+            market_account.claim_service_fee_percentage,
+            discount_bps,
+        )?
+    } else {
+        0
+    };
+
+    let reward_amount = calculate_policy_reward(market_account, betting_account, receive_tokens)?;
+    let reward_amount = scale_reward_to_budget(market_account, answer_account, reward_amount)?;
+
+    Ok(ClaimAmounts {
+        receive_tokens: receive_tokens - service_fee,
+        service_fee,
+        reward_amount,
+    })
+}
+
+/// Payout locked in for `amount` staked at decimal `odds`.
+pub fn calculate_fixed_odds_payout(amount: u64, odds: u64) -> Result<u64> {
+    let payout = (amount as u128)
+        .checked_mul(odds as u128)
+        .and_then(|result| result.checked_div(ODDS_BASIS_POINTS as u128))
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    u64::try_from(payout).map_err(|_| ProgramErrorCode::Overflow.into())
+}
+
 pub fn is_retrieve_available(market_account: &MarketAccount, clock: &Clock) -> Result<bool> {
     require!(
         market_account.status == MarketStatus::Success
@@ -254,6 +784,57 @@ pub fn is_retrieve_available(market_account: &MarketAccount, clock: &Clock) -> R
     Ok(is_available)
 }
 
+/// Check that the instruction right before the current one is an ed25519
+/// program instruction verifying `signer`'s signature over `message`.
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, ProgramErrorCode::InvalidSignature);
+
+    let instruction = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    require!(
+        instruction.program_id == ed25519_program::ID && instruction.accounts.is_empty(),
+        ProgramErrorCode::InvalidSignature
+    );
+
+    // Layout: [num_signatures: u8, padding: u8, offsets: 7 x u16, ...data]
+    let data = &instruction.data;
+    require!(data.len() >= 16 && data[0] == 1, ProgramErrorCode::InvalidSignature);
+
+    let read_u16 = |index: usize| u16::from_le_bytes([data[index], data[index + 1]]);
+    let signature_instruction_index = read_u16(4);
+    let public_key_offset = read_u16(6) as usize;
+    let public_key_instruction_index = read_u16(8);
+    let message_data_offset = read_u16(10) as usize;
+    let message_data_size = read_u16(12) as usize;
+    let message_instruction_index = read_u16(14);
+
+    // Every offset has to point into the ed25519 instruction itself.
+    require!(
+        signature_instruction_index == u16::MAX
+            && public_key_instruction_index == u16::MAX
+            && message_instruction_index == u16::MAX,
+        ProgramErrorCode::InvalidSignature
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(ProgramErrorCode::InvalidSignature)?;
+    let signed_message = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(ProgramErrorCode::InvalidSignature)?;
+
+    require!(
+        public_key == signer.as_ref() && signed_message == message,
+        ProgramErrorCode::InvalidSignature
+    );
+
+    Ok(())
+}
+
 pub fn is_supported_mint(mint_account: &InterfaceAccount<Mint>) -> Result<bool> {
     let mint_info = mint_account.to_account_info();
     if *mint_info.owner == Token::id() {
@@ -272,3 +853,423 @@ pub fn is_supported_mint(mint_account: &InterfaceAccount<Mint>) -> Result<bool>
     }
     Ok(true)
 }
+
+/// LMSR math runs in unsigned Q64.64 fixed point.
+const FIXED_ONE: u128 = 1 << 64;
+/// ln(2) in Q64.64.
+const FIXED_LN_2: u128 = 12_786_308_645_202_655_660;
+/// `exp(-x)` is below one Q64.64 unit from here on.
+const FIXED_EXP_CUTOFF: u128 = 45 * FIXED_ONE;
+/// Fractional bits kept on LMSR costs, which are token amounts.
+const COST_FRAC_BITS: u32 = 32;
+
+/// `exp(-x)` for `x >= 0`, rounded down. `x = k * ln(2) + r` with
+/// `0 <= r < ln(2)`, and `exp(-r)` is summed as its Taylor series.
+fn fixed_exp_neg(x: u128) -> u128 {
+    if x >= FIXED_EXP_CUTOFF {
+        return 0;
+    }
+
+    let k = x / FIXED_LN_2;
+    let r = x - k * FIXED_LN_2;
+
+    let (mut positive, mut negative) = (FIXED_ONE, 0u128);
+    let mut term = FIXED_ONE;
+    let mut i = 1u128;
+    while term > 0 {
+        term = ((term * r) >> 64) / i;
+        if i % 2 == 0 {
+            positive += term;
+        } else {
+            negative += term;
+        }
+        i += 1;
+    }
+
+    (positive - negative) >> k
+}
+
+/// `ln(x)` for `x >= 1`, rounded down. `x = 2^k * y` with `1 <= y < 2`, and
+/// `ln(y) = 2 * atanh((y - 1) / (y + 1))` is summed as its series.
+fn fixed_ln(x: u128) -> u128 {
+    let k = 127 - x.leading_zeros() - 64;
+    let y = x >> k;
+
+    let z = ((y - FIXED_ONE) << 64) / (y + FIXED_ONE);
+    let z_squared = (z * z) >> 64;
+
+    let mut sum = 0u128;
+    let mut power = z;
+    let mut i = 1u128;
+    while power > 0 {
+        sum += power / i;
+        power = (power * z_squared) >> 64;
+        i += 2;
+    }
+
+    k as u128 * FIXED_LN_2 + 2 * sum
+}
+
+/// Largest share count and `sum(exp((q_i - q_max) / b))` in Q64.64. The
+/// largest outcome contributes exactly one, so the sum is at least one.
+fn lmsr_exp_sum(outcome_shares: &[u64], liquidity_param: u64) -> Result<(u64, u128)> {
+    require!(
+        liquidity_param > 0 && !outcome_shares.is_empty(),
+        ProgramErrorCode::InvalidLiquidityParam
+    );
+
+    let max_shares = *outcome_shares.iter().max().unwrap_or(&0);
+    let sum = outcome_shares
+        .iter()
+        .map(|shares| {
+            let exponent = (((max_shares - shares) as u128) << 64) / liquidity_param as u128;
+            fixed_exp_neg(exponent)
+        })
+        .sum();
+
+    Ok((max_shares, sum))
+}
+
+/// LMSR cost function `C(q) = b * ln(sum(exp(q_i / b)))`, evaluated as
+/// `q_max + b * ln(sum(exp((q_i - q_max) / b)))` so the large part stays an
+/// exact integer. The result carries `COST_FRAC_BITS` fractional bits.
+pub fn lmsr_cost(outcome_shares: &[u64], liquidity_param: u64) -> Result<u128> {
+    let (max_shares, sum) = lmsr_exp_sum(outcome_shares, liquidity_param)?;
+    let ln_sum = fixed_ln(sum);
+
+    let b = liquidity_param as u128;
+    let scaled_ln_sum = (b * (ln_sum >> 64) << COST_FRAC_BITS)
+        + ((b * (ln_sum & (FIXED_ONE - 1))) >> (64 - COST_FRAC_BITS));
+
+    ((max_shares as u128) << COST_FRAC_BITS)
+        .checked_add(scaled_ln_sum)
+        .ok_or(ProgramErrorCode::Overflow.into())
+}
+
+/// Instantaneous LMSR price of an outcome in Q64.64, between 0 and 1,
+/// rounded down.
+pub fn lmsr_price(outcome_shares: &[u64], index: usize, liquidity_param: u64) -> Result<u128> {
+    require!(
+        index < outcome_shares.len(),
+        ProgramErrorCode::InvalidLiquidityParam
+    );
+
+    let (max_shares, sum) = lmsr_exp_sum(outcome_shares, liquidity_param)?;
+    let exponent =
+        (((max_shares - outcome_shares[index]) as u128) << 64) / liquidity_param as u128;
+
+    // the sum is rounded up so the price never is
+    Ok((fixed_exp_neg(exponent) << 32) / ((sum + (1 << 32) - 1) >> 32))
+}
+
+/// Whole tokens that cover the fixed point error of a cost over
+/// `outcome_count` outcomes. Each `exp` is off by a few dozen Q64.64 units at
+/// most and `ln` by about a hundred, scaled by `b`; the bound is generous.
+fn lmsr_rounding_margin(outcome_count: usize, liquidity_param: u64) -> u64 {
+    let error_units = 32 * outcome_count as u128 + 128;
+    (((liquidity_param as u128 * error_units) >> 64) as u64).saturating_add(1)
+}
+
+/// Subsidy the creator has to lock so the market maker can cover its worst
+/// case loss of `b * ln(n)`, rounded up.
+pub fn lmsr_funding_amount(outcome_count: usize, liquidity_param: u64) -> Result<u64> {
+    let cost = lmsr_cost(&vec![0; outcome_count], liquidity_param)?;
+    to_token_amount(ceil_cost(cost), lmsr_rounding_margin(outcome_count, liquidity_param))
+}
+
+/// Tokens a trader pays to buy `shares` of an outcome, rounded up.
+pub fn lmsr_buy_cost(
+    outcome_shares: &[u64],
+    index: usize,
+    shares: u64,
+    liquidity_param: u64,
+) -> Result<u64> {
+    let mut next_shares = outcome_shares.to_vec();
+    next_shares[index] = next_shares[index]
+        .checked_add(shares)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let cost = lmsr_cost(&next_shares, liquidity_param)?
+        .saturating_sub(lmsr_cost(outcome_shares, liquidity_param)?);
+    to_token_amount(
+        ceil_cost(cost),
+        lmsr_rounding_margin(outcome_shares.len(), liquidity_param),
+    )
+}
+
+/// Tokens a trader receives for selling `shares` of an outcome, rounded down.
+pub fn lmsr_sell_proceeds(
+    outcome_shares: &[u64],
+    index: usize,
+    shares: u64,
+    liquidity_param: u64,
+) -> Result<u64> {
+    let mut next_shares = outcome_shares.to_vec();
+    next_shares[index] = next_shares[index]
+        .checked_sub(shares)
+        .ok_or(ProgramErrorCode::InsufficientShares)?;
+
+    let proceeds = lmsr_cost(outcome_shares, liquidity_param)?
+        .saturating_sub(lmsr_cost(&next_shares, liquidity_param)?);
+    let margin = lmsr_rounding_margin(outcome_shares.len(), liquidity_param);
+    Ok(u64::try_from(proceeds >> COST_FRAC_BITS)
+        .map_err(|_| ProgramErrorCode::MathOperationError)?
+        .saturating_sub(margin))
+}
+
+fn ceil_cost(cost: u128) -> u128 {
+    (cost + (1 << COST_FRAC_BITS) - 1) >> COST_FRAC_BITS
+}
+
+fn to_token_amount(value: u128, margin: u64) -> Result<u64> {
+    u64::try_from(value)
+        .ok()
+        .and_then(|value| value.checked_add(margin))
+        .ok_or(ProgramErrorCode::MathOperationError.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    const B: u64 = 1_000_000;
+
+    /// Reference LMSR cost in floating point, for tests only.
+    fn float_cost(outcome_shares: &[u64], liquidity_param: u64) -> f64 {
+        let b = liquidity_param as f64;
+        let max = *outcome_shares.iter().max().unwrap() as f64;
+        let sum: f64 = outcome_shares
+            .iter()
+            .map(|shares| ((*shares as f64 - max) / b).exp())
+            .sum();
+        max + b * sum.ln()
+    }
+
+    #[test]
+    fn test_fixed_exp_and_ln() {
+        for x in [0.0, 0.25, 0.5, 1.0, 3.7, 20.0] {
+            let fixed = fixed_exp_neg((x * FIXED_ONE as f64) as u128) as f64 / FIXED_ONE as f64;
+            assert!((fixed - (-x as f64).exp()).abs() < 1e-12);
+        }
+        for x in [1.0, 1.5, 2.0, 3.3, 16.0, 200.0] {
+            let fixed = fixed_ln((x * FIXED_ONE as f64) as u128) as f64 / FIXED_ONE as f64;
+            assert!((fixed - (x as f64).ln()).abs() < 1e-12);
+        }
+        assert_eq!(fixed_exp_neg(0), FIXED_ONE);
+        assert_eq!(fixed_ln(FIXED_ONE), 0);
+    }
+
+    #[test]
+    fn test_lmsr_cost_matches_float() {
+        let shares = [2_500_000, 100_000, 0];
+        let cost = lmsr_cost(&shares, B).unwrap() as f64 / (1u64 << COST_FRAC_BITS) as f64;
+        assert!((cost - float_cost(&shares, B)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_lmsr_funding_amount() {
+        // b * ln(2) for a binary market, rounded up, plus the margin
+        assert_eq!(lmsr_funding_amount(2, B).unwrap(), 693_149);
+        // b * ln(4)
+        assert_eq!(lmsr_funding_amount(4, B).unwrap(), 1_386_296);
+    }
+
+    #[test]
+    fn test_lmsr_prices_sum_to_one() {
+        let shares = [2_500_000, 100_000, 0];
+        let total: u128 = (0..shares.len())
+            .map(|i| lmsr_price(&shares, i, B).unwrap())
+            .sum();
+        assert!(total <= FIXED_ONE);
+        assert!(FIXED_ONE - total < 1 << 34);
+        assert!(lmsr_price(&shares, 0, B).unwrap() > lmsr_price(&shares, 1, B).unwrap());
+    }
+
+    #[test]
+    fn test_lmsr_buy_then_sell_never_pays_out_more() {
+        let shares = [300_000, 50_000];
+        let cost = lmsr_buy_cost(&shares, 1, 400_000, B).unwrap();
+        let proceeds = lmsr_sell_proceeds(&[300_000, 450_000], 1, 400_000, B).unwrap();
+        assert!(cost > 0);
+        assert!(proceeds < cost);
+        assert!(cost - proceeds <= 3);
+    }
+
+    #[test]
+    fn test_lmsr_large_amounts_round_toward_vault() {
+        // beyond f64's 2^53 integer range
+        let b = 1u64 << 60;
+        let shares = [(1u64 << 62) + 12_345, 1u64 << 61];
+        let cost = lmsr_buy_cost(&shares, 1, 1u64 << 58, b).unwrap();
+        let next_shares = [shares[0], shares[1] + (1u64 << 58)];
+        let proceeds = lmsr_sell_proceeds(&next_shares, 1, 1u64 << 58, b).unwrap();
+        assert!(proceeds < cost);
+        assert!(cost < 1u64 << 58);
+    }
+
+    #[test]
+    fn test_lmsr_buy_cost_bounded_by_shares() {
+        // a share never costs more than the one token it pays out
+        let cost = lmsr_buy_cost(&[0, 0], 0, 10_000_000, B).unwrap();
+        assert!(cost < 10_000_000);
+        assert!(cost > 10_000_000 - lmsr_funding_amount(2, B).unwrap());
+    }
+
+    #[test]
+    fn test_lmsr_sell_more_than_outstanding() {
+        assert!(lmsr_sell_proceeds(&[10, 0], 0, 11, B).is_err());
+    }
+
+    fn reward_market(total_tokens: u64, stake_time: u128, reward_budget: u64) -> MarketAccount {
+        MarketAccount {
+            version: MarketAccount::VERSION,
+            exist: true,
+            market_key: 1,
+            status: MarketStatus::Success,
+            finish_time: SECONDS_IN_A_YEAR,
+            market_total_tokens: total_tokens,
+            reward_apr: 1_000,
+            reward_budget,
+            reward_stake_time: stake_time,
+            reward_bet_count: 1,
+            ..Default::default()
+        }
+    }
+
+    fn reward_bet(tokens: u64, create_time: u64) -> BettingAccount {
+        BettingAccount {
+            version: BettingAccount::VERSION,
+            market_key: 1,
+            answer_key: 1,
+            tokens,
+            create_time,
+            exist: true,
+            ..Default::default()
+        }
+    }
+
+    fn reward_answers(winning_tokens: u64) -> AnswerAccount {
+        AnswerAccount {
+            version: AnswerAccount::VERSION,
+            answers: vec![Answer {
+                answer_key: 0,
+                answer_total_tokens: winning_tokens,
+            }],
+            exist: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_claim_service_fee_discount() {
+        // 5% fee on 10_000
+        assert_eq!(calculate_claim_service_fee(10_000, 500, 0).unwrap(), 500);
+        assert_eq!(calculate_claim_service_fee(10_000, 500, 2_500).unwrap(), 375);
+        assert_eq!(calculate_claim_service_fee(10_000, 500, 10_000).unwrap(), 0);
+        assert_eq!(calculate_claim_service_fee(0, 500, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_linear_reward_only_for_losing_bets() {
+        let market = reward_market(1_000, 0, 100);
+        let bet = reward_bet(1_000, 0);
+        assert_eq!(calculate_policy_reward(&market, &bet, 0).unwrap(), 100);
+        assert_eq!(calculate_policy_reward(&market, &bet, 1_500).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_early_bird_multiplier_decays_to_one() {
+        let year = SECONDS_IN_A_YEAR;
+        assert_eq!(calculate_early_bird_multiplier(30_000, 0, 0, year).unwrap(), 30_000);
+        assert_eq!(calculate_early_bird_multiplier(30_000, 0, year / 2, year).unwrap(), 20_000);
+        assert_eq!(calculate_early_bird_multiplier(30_000, 0, year, year).unwrap(), 10_000);
+        assert!(calculate_early_bird_multiplier(30_000, 10, 0, year).is_err());
+    }
+
+    #[test]
+    fn test_early_bird_reward() {
+        let mut market = reward_market(1_000, 0, 1_000);
+        market.reward_policy = RewardPolicy::EarlyBird {
+            max_multiplier: 30_000,
+        };
+        // 10% APR for a full year, placed at approval for the full 3x
+        assert_eq!(calculate_policy_reward(&market, &reward_bet(1_000, 0), 0).unwrap(), 300);
+        assert_eq!(calculate_reward_liability(&market, &reward_answers(0)).unwrap(), 300);
+    }
+
+    #[test]
+    fn test_participation_reward() {
+        let mut market = reward_market(1_000, 0, 1_000);
+        market.reward_policy = RewardPolicy::Participation { amount: 25 };
+        market.reward_bet_count = 4;
+        let bet = reward_bet(1_000, 0);
+        assert_eq!(calculate_policy_reward(&market, &bet, 0).unwrap(), 25);
+        assert_eq!(calculate_policy_reward(&market, &bet, 1_500).unwrap(), 25);
+        assert_eq!(calculate_reward_liability(&market, &reward_answers(0)).unwrap(), 100);
+    }
+
+    #[test]
+    fn test_consolation_reward() {
+        let mut market = reward_market(1_000, 0, 1_000);
+        market.reward_policy = RewardPolicy::Consolation { basis_points: 500 };
+        let bet = reward_bet(400, 0);
+        assert_eq!(calculate_policy_reward(&market, &bet, 0).unwrap(), 20);
+        assert_eq!(calculate_policy_reward(&market, &bet, 600).unwrap(), 0);
+        assert_eq!(calculate_reward_liability(&market, &reward_answers(0)).unwrap(), 50);
+    }
+
+    #[test]
+    fn test_reward_within_budget_is_not_scaled() {
+        // 1_000 tokens staked for a full year at 10% owes 100
+        let market = reward_market(1_000, 0, 100);
+        assert_eq!(calculate_reward_liability(&market, &reward_answers(0)).unwrap(), 100);
+        assert_eq!(scale_reward_to_budget(&market, &reward_answers(0), 60).unwrap(), 60);
+    }
+
+    #[test]
+    fn test_reward_over_budget_is_scaled_pro_rata() {
+        let mut market = reward_market(1_000, 0, 50);
+        assert_eq!(scale_reward_to_budget(&market, &reward_answers(0), 60).unwrap(), 30);
+        assert_eq!(scale_reward_to_budget(&market, &reward_answers(0), 40).unwrap(), 20);
+
+        market.reward_paid = 45;
+        assert_eq!(scale_reward_to_budget(&market, &reward_answers(0), 40).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_reward_liability_counts_losing_bets_only() {
+        let mut market = reward_market(1_000, 0, 100);
+        // 600 on the winning answer earn nothing, 400 lost for a full year
+        assert_eq!(calculate_reward_liability(&market, &reward_answers(600)).unwrap(), 40);
+        assert_eq!(scale_reward_to_budget(&market, &reward_answers(600), 40).unwrap(), 40);
+
+        market.reward_policy = RewardPolicy::Consolation { basis_points: 500 };
+        assert_eq!(calculate_reward_liability(&market, &reward_answers(600)).unwrap(), 20);
+
+        // losing bets placed half way through cannot have earned more
+        market.reward_policy = RewardPolicy::Linear;
+        market.approve_time = SECONDS_IN_A_YEAR / 2;
+        assert_eq!(calculate_reward_liability(&market, &reward_answers(600)).unwrap(), 20);
+
+        market.status = MarketStatus::Adjourn;
+        assert_eq!(calculate_reward_liability(&market, &reward_answers(600)).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_flat_creator_fee_capped_by_pool() {
+        let market = MarketAccount {
+            creator_fee: 500,
+            creator_fee_percentage: 1_000,
+            service_fee_percentage: 500,
+            ..Default::default()
+        };
+        let fees = calculate_market_fees(&market, 10_000).unwrap();
+        assert_eq!((fees.creator_fee, fees.service_fee), (1_500, 500));
+        assert_eq!(fees.reward_base_tokens, 8_000);
+
+        let fees = calculate_market_fees(&market, 500).unwrap();
+        assert_eq!((fees.creator_fee, fees.service_fee), (475, 25));
+        assert_eq!(fees.reward_base_tokens, 0);
+    }
+}
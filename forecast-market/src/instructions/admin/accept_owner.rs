@@ -0,0 +1,61 @@
+use std::ops::DerefMut;
+
+use crate::{
+    error::ProgramErrorCode,
+    instructions::emit_config_changes,
+    states::{ConfigAccount, OwnerTransferAccount, CONFIG_SEED, OWNER_TRANSFER_SEED},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptOwner<'info> {
+    #[account(
+    mut,
+    constraint = (new_owner.key() == owner_transfer_account.pending_owner) @ ProgramErrorCode::Unauthorized
+)]
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        close = new_owner,
+        seeds = [OWNER_TRANSFER_SEED.as_bytes()],
+        bump = owner_transfer_account.bump,
+    )]
+    pub owner_transfer_account: Account<'info, OwnerTransferAccount>,
+}
+
+#[event]
+pub struct OwnerTransferAccepted {
+    pub previous_owner: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp as u64 >= ctx.accounts.owner_transfer_account.executable_time(),
+        ProgramErrorCode::OwnerTransferTooEarly
+    );
+
+    let config_account = ctx.accounts.config_account.deref_mut();
+
+    let before = config_account.clone();
+    let previous_owner = config_account.owner;
+    config_account.owner = ctx.accounts.new_owner.key();
+
+    emit_config_changes(&before, config_account);
+
+    emit!(OwnerTransferAccepted {
+        previous_owner,
+        owner: config_account.owner,
+    });
+
+    Ok(())
+}
@@ -2,18 +2,27 @@ use std::ops::DerefMut;
 
 use anchor_lang::prelude::*;
 
-use crate::{error::ProgramErrorCode, Answer, AnswerAccount, ConfigAccount, MarketAccount, ANSWER_SEED, MAX_ANWSER};
+use crate::{error::ProgramErrorCode, Answer, AnswerAccount, ConfigAccount, MarketAccount, MarketType, ANSWER_SEED, MAX_ANWSER, Role, Roles, ROLES_SEED};
 
 #[derive(Accounts)]
 pub struct AddAnswer<'info> {
     #[account(
         mut,
-        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+        constraint = market_account.is_operator(&owner.key()) || roles_account.has_role(&owner.key(), Role::MarketOperator, &config_account.owner) @ ProgramErrorCode::Unauthorized
     )]    
     pub owner: Signer<'info>,
     #[account(mut)]
     pub config_account: Account<'info, ConfigAccount>,
-    #[account(mut)]
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+      mut,
+      constraint = market_account.market_type == MarketType::Parimutuel @ ProgramErrorCode::AnswersLocked,
+      constraint = market_account.outcome_collateral_tokens == 0 @ ProgramErrorCode::AnswersLocked,
+    )]
     pub market_account: Account<'info, MarketAccount>,
     #[account(        
       init_if_needed,
@@ -36,6 +45,7 @@ pub fn add_answer_keys(ctx: Context<AddAnswer>, answer_keys: Vec<u64>) -> Result
     let answer_account = ctx.accounts.answer_account.deref_mut();
 
     if !answer_account.exist {
+        answer_account.version = AnswerAccount::VERSION;
         answer_account.answers = Vec::with_capacity(MAX_ANWSER);
         answer_account.exist = true;
     }
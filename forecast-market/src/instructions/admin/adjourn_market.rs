@@ -2,22 +2,36 @@ use std::ops::DerefMut;
 
 use anchor_lang::prelude::*;
 
-use crate::{error::ProgramErrorCode, ConfigAccount, MarketAccount, MarketStatus};
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, MarketAccount, MarketStatus, Role, Roles,
+    RESOLUTION_PROPOSAL_SEED, ROLES_SEED,
+};
 
 #[derive(Accounts)]
 pub struct AdjournMarket<'info> {
-    #[account(
-      mut,
-      constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
-    )]
+    /// See `authorize_adjournment`.
+    #[account(mut)]
     pub owner: Signer<'info>,
     #[account(mut)]
     pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
     #[account(
       mut,
-      constraint = market_account.status == MarketStatus::Finished @ ProgramErrorCode::MarketNotFinished
+      constraint = market_account.status == MarketStatus::Finished || market_account.status == MarketStatus::Disputed @ ProgramErrorCode::MarketNotFinished
     )]
     pub market_account: Account<'info, MarketAccount>,
+    /// CHECK: the market's resolution proposal PDA, which must not exist;
+    /// a proposal's bonds are only released by the oracle instructions.
+    #[account(
+      seeds = [RESOLUTION_PROPOSAL_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+      constraint = resolution_proposal.data_is_empty() @ ProgramErrorCode::ProposalPending,
+    )]
+    pub resolution_proposal: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
@@ -26,7 +40,35 @@ pub struct MarketAdjourned {
     pub market_key: u64,
 }
 
+/// Markets with a committee are left to it until its members disagree, and
+/// can then be adjourned by a resolver. Other markets can be adjourned by a
+/// resolver, or by their operator unless they are disputed.
+fn authorize_adjournment(ctx: &Context<AdjournMarket>) -> Result<()> {
+    let market_account = &ctx.accounts.market_account;
+    let signer = ctx.accounts.owner.key();
+
+    let is_resolver = ctx.accounts.roles_account.has_role(
+        &signer,
+        Role::Resolver,
+        &ctx.accounts.config_account.owner,
+    );
+
+    if market_account.status == MarketStatus::Disputed {
+        require!(is_resolver, ProgramErrorCode::Unauthorized);
+    } else {
+        require!(
+            market_account.resolver_committee == Pubkey::default()
+                && (is_resolver || market_account.is_operator(&signer)),
+            ProgramErrorCode::Unauthorized
+        );
+    }
+
+    Ok(())
+}
+
 pub fn adjourn_market(ctx: Context<AdjournMarket>) -> Result<()> {
+    authorize_adjournment(&ctx)?;
+
     let market_account = ctx.accounts.market_account.deref_mut();
 
     let clock = Clock::get()?;
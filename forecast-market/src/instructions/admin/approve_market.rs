@@ -1,35 +1,124 @@
 use std::ops::DerefMut;
 
 use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
 
-use crate::{error::ProgramErrorCode, ConfigAccount, MarketAccount, MarketStatus};
+use crate::helper::transfer_from_pool_vault_to_user;
+use crate::{
+    constant::BASIS_POINTS, error::ProgramErrorCode, ConfigAccount, MarketAccount, MarketStatus,
+    RewardPolicy, CONFIG_SEED, MARKET_REWARD_SEED, MARKET_SEED,
+    Role, Roles, ROLES_SEED,
+};
 
 #[derive(Accounts)]
 pub struct ApproveMarket<'info> {
     #[account(
         mut,
-        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+        constraint = roles_account.has_role(&owner.key(), Role::MarketOperator, &config_account.owner) @ ProgramErrorCode::Unauthorized
     )]
     pub owner: Signer<'info>,
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
     pub config_account: Account<'info, ConfigAccount>,
-    #[account(mut)]
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+        mut,
+        seeds = [MARKET_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump = market_account.bump,
+        constraint = market_account.status == MarketStatus::Draft @ ProgramErrorCode::MarketNotDraft,
+    )]
     pub market_account: Account<'info, MarketAccount>,
+    #[account(
+        address = config_account.reward_mint @ ProgramErrorCode::InvalidRewardMint
+    )]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = config_account
+    )]
+    pub vault_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// Holds the market's reward budget; claims are paid from it.
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [MARKET_REWARD_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = market_account
+    )]
+    pub market_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
 #[event]
 pub struct MarketApproved {
     pub market_key: u64,
+    pub reward_apr: u64,
+    pub reward_budget: u64,
+    pub reward_policy: RewardPolicy,
 }
 
-pub fn approve_market(ctx: Context<ApproveMarket>) -> Result<()> {
+pub fn approve_market(
+    ctx: Context<ApproveMarket>,
+    reward_budget: u64,
+    reward_policy: RewardPolicy,
+) -> Result<()> {
+    match reward_policy {
+        RewardPolicy::EarlyBird { max_multiplier } => require!(
+            max_multiplier >= BASIS_POINTS as u64,
+            ProgramErrorCode::InvalidRewardPolicy
+        ),
+        RewardPolicy::Consolation { basis_points } => require!(
+            basis_points <= BASIS_POINTS as u64,
+            ProgramErrorCode::InvalidRewardPolicy
+        ),
+        RewardPolicy::Linear | RewardPolicy::Participation { .. } => {}
+    }
+
+    // Reserve the budget in the market's reward escrow, so it cannot be
+    // promised to another market as well.
+    let reward_seeds: &[&[u8]] = &[CONFIG_SEED.as_bytes(), &[ctx.accounts.config_account.bump]];
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.vault_reward_token_account.to_account_info(),
+        &ctx.accounts.market_reward_token_account.to_account_info(),
+        ctx.accounts.reward_mint.clone(),
+        &ctx.accounts.config_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        reward_budget,
+        &[&reward_seeds],
+    )?;
+    ctx.accounts.market_reward_token_account.reload()?;
+
     let market_account: &mut MarketAccount = ctx.accounts.market_account.deref_mut();
 
     market_account.status = MarketStatus::Approve;
+    // Betting opens now, which is where early bird multipliers decay from.
+    let clock = Clock::get()?;
+    market_account.approve_time = clock.unix_timestamp as u64;
+    // Snapshot the reward terms so later `update_reward_config` calls only
+    // affect markets approved afterwards. The budget is what the escrow
+    // holds, net of any transfer fee.
+    market_account.reward_apr = ctx.accounts.config_account.reward_apr;
+    market_account.reward_budget = ctx.accounts.market_reward_token_account.amount;
+    market_account.reward_policy = reward_policy;
 
     emit!(MarketApproved {
         market_key: market_account.market_key.clone(),
+        reward_apr: market_account.reward_apr,
+        reward_budget: market_account.reward_budget,
+        reward_policy,
     });
 
     Ok(())
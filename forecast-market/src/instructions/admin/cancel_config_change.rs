@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, PendingConfigChange, Role, Roles, CONFIG_SEED,
+    PENDING_CONFIG_CHANGE_SEED, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    #[account(
+        constraint = (owner.key() == pending_config_change.proposer || roles_account.has_role(&owner.key(), Role::Admin, &config_account.owner)) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    /// CHECK: receives the rent of the cancelled change.
+    #[account(
+        mut,
+        constraint = proposer.key() == pending_config_change.proposer @ ProgramErrorCode::Unauthorized
+    )]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [PENDING_CONFIG_CHANGE_SEED.as_bytes(), &pending_config_change.change_id.to_le_bytes()],
+        bump = pending_config_change.bump,
+    )]
+    pub pending_config_change: Account<'info, PendingConfigChange>,
+}
+
+#[event]
+pub struct ConfigChangeCancelled {
+    pub change_id: u64,
+    pub cancelled_by: Pubkey,
+}
+
+/// Drop a scheduled change. Its proposer or an admin may cancel it.
+pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+    emit!(ConfigChangeCancelled {
+        change_id: ctx.accounts.pending_config_change.change_id,
+        cancelled_by: ctx.accounts.owner.key(),
+    });
+
+    Ok(())
+}
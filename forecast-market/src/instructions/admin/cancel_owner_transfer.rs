@@ -0,0 +1,43 @@
+use crate::{
+    error::ProgramErrorCode,
+    states::{ConfigAccount, OwnerTransferAccount, CONFIG_SEED, OWNER_TRANSFER_SEED},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelOwnerTransfer<'info> {
+    #[account(
+    mut,
+    constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [OWNER_TRANSFER_SEED.as_bytes()],
+        bump = owner_transfer_account.bump,
+    )]
+    pub owner_transfer_account: Account<'info, OwnerTransferAccount>,
+}
+
+#[event]
+pub struct OwnerTransferCancelled {
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+pub fn cancel_owner_transfer(ctx: Context<CancelOwnerTransfer>) -> Result<()> {
+    emit!(OwnerTransferCancelled {
+        owner: ctx.accounts.owner.key(),
+        pending_owner: ctx.accounts.owner_transfer_account.pending_owner,
+    });
+
+    Ok(())
+}
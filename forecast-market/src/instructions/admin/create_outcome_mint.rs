@@ -0,0 +1,96 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+use crate::{
+    error::ProgramErrorCode, AnswerAccount, ConfigAccount, MarketAccount, OutcomeMintBump,
+    OutcomeMintsAccount, ANSWER_SEED, MARKET_SEED, OUTCOME_MINTS_SEED, OUTCOME_MINT_SEED,
+    Role, Roles, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(answer_key: u64)]
+pub struct CreateOutcomeMint<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::MarketOperator, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+        constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub bet_mint: Box<Account<'info, Mint>>,
+    #[account(
+      seeds = [MARKET_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump = market_account.bump,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+    #[account(
+        init,
+        payer = owner,
+        seeds = [OUTCOME_MINT_SEED.as_bytes(), &market_account.market_key.to_le_bytes(), &answer_key.to_le_bytes()],
+        bump,
+        mint::decimals = bet_mint.decimals,
+        mint::authority = market_account,
+    )]
+    pub outcome_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = OutcomeMintsAccount::MAX_SIZE,
+        seeds = [OUTCOME_MINTS_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump,
+    )]
+    pub outcome_mints_account: Box<Account<'info, OutcomeMintsAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event]
+pub struct OutcomeMintCreated {
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub outcome_mint: Pubkey,
+}
+
+pub fn create_outcome_mint(ctx: Context<CreateOutcomeMint>, answer_key: u64) -> Result<()> {
+    if !ctx
+        .accounts
+        .answer_account
+        .answers
+        .iter()
+        .any(|answer| answer.answer_key == answer_key)
+    {
+        return Err(ProgramErrorCode::AnswerNotExists.into());
+    }
+
+    let outcome_mints_account = ctx.accounts.outcome_mints_account.deref_mut();
+    outcome_mints_account.bump = ctx.bumps.outcome_mints_account;
+    outcome_mints_account.market_key = ctx.accounts.market_account.market_key;
+    outcome_mints_account.mints.push(OutcomeMintBump {
+        answer_key,
+        bump: ctx.bumps.outcome_mint,
+    });
+
+    emit!(OutcomeMintCreated {
+        market_key: ctx.accounts.market_account.market_key,
+        answer_key,
+        outcome_mint: ctx.accounts.outcome_mint.key(),
+    });
+
+    Ok(())
+}
@@ -3,20 +3,34 @@ use std::ops::DerefMut;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 
-use crate::{error::ProgramErrorCode, ConfigAccount, MarketAccount, MARKET_SEED};
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, FeeSchedule, MarketAccount, FEE_SCHEDULE_SEED,
+    MARKET_SEED, MAX_TITLE_LEN,
+    Role, Roles, ROLES_SEED,
+};
 
 #[derive(Accounts)]
 #[instruction(market_key: u64)]
 pub struct DraftMarket<'info> {
     #[account(
         mut,
-        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+        constraint = roles_account.has_role(&owner.key(), Role::MarketOperator, &config_account.owner) @ ProgramErrorCode::Unauthorized
     )]
     pub owner: Signer<'info>,
     #[account(mut)]
     pub bet_mint: Account<'info, Mint>,
     #[account(mut)]
     pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
     #[account(
         init,
         payer = owner,
@@ -35,7 +49,7 @@ pub struct MarketDrafted {
     pub create_fee: u64,
     pub creator_fee_percentage: u64,
     pub service_fee_percentage: u64,
-    pub approve_time: u64,
+    pub draft_time: u64,
 }
 
 pub fn draft_market(
@@ -44,13 +58,23 @@ pub fn draft_market(
     creator: Pubkey,
     title: String,
     create_fee: u64,
-    creator_fee_percentage: u64,
-    service_fee_percentage: u64,
+    creator_fee_percentage: Option<u64>,
+    service_fee_percentage: Option<u64>,
 ) -> Result<()> {
+    let fee_schedule = &ctx.accounts.fee_schedule;
+    let (default_creator_fee_percentage, default_service_fee_percentage) =
+        fee_schedule.fees_for(&creator);
+    let creator_fee_percentage = creator_fee_percentage.unwrap_or(default_creator_fee_percentage);
+    let service_fee_percentage = service_fee_percentage.unwrap_or(default_service_fee_percentage);
+    fee_schedule.validate(creator_fee_percentage, service_fee_percentage)?;
+
+    require!(title.len() <= MAX_TITLE_LEN, ProgramErrorCode::TitleTooLong);
+
     let market_account = ctx.accounts.market_account.deref_mut();
 
     let clock = Clock::get()?;
 
+    market_account.version = MarketAccount::VERSION;
     market_account.bump = ctx.bumps.market_account;
     market_account.bet_mint = ctx.accounts.bet_mint.key();
     market_account.creator = creator;
@@ -68,7 +92,7 @@ pub fn draft_market(
         create_fee,
         creator_fee_percentage,
         service_fee_percentage,
-        approve_time: clock.unix_timestamp as u64,
+        draft_time: clock.unix_timestamp as u64,
     });
 
     Ok(())
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::{emit_config_changes, ConfigValueChanged};
+use crate::{
+    error::ProgramErrorCode, ConfigField, ConfigAccount, ConfigChange, ConfigTimelockAccount,
+    PendingConfigChange, CONFIG_SEED, CONFIG_TIMELOCK_SEED, PENDING_CONFIG_CHANGE_SEED,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    pub executor: Signer<'info>,
+    /// CHECK: receives the rent of the executed change.
+    #[account(
+        mut,
+        constraint = proposer.key() == pending_config_change.proposer @ ProgramErrorCode::Unauthorized
+    )]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        mut,
+        seeds = [CONFIG_TIMELOCK_SEED.as_bytes()],
+        bump = config_timelock_account.bump,
+    )]
+    pub config_timelock_account: Account<'info, ConfigTimelockAccount>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [PENDING_CONFIG_CHANGE_SEED.as_bytes(), &pending_config_change.change_id.to_le_bytes()],
+        bump = pending_config_change.bump,
+    )]
+    pub pending_config_change: Account<'info, PendingConfigChange>,
+}
+
+#[event]
+pub struct ConfigChangeExecuted {
+    pub change_id: u64,
+    pub change: ConfigChange,
+}
+
+/// Apply a scheduled change once its delay has passed. Anyone may execute it.
+pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+    let pending_config_change = &ctx.accounts.pending_config_change;
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp as u64 >= pending_config_change.executable_time,
+        ProgramErrorCode::ConfigChangeTooEarly
+    );
+
+    let before = ctx.accounts.config_account.clone().into_inner();
+    let old_delay = ctx.accounts.config_timelock_account.delay;
+
+    pending_config_change.change.apply(
+        &mut ctx.accounts.config_account,
+        &mut ctx.accounts.config_timelock_account,
+    );
+
+    emit_config_changes(&before, &ctx.accounts.config_account);
+
+    let new_delay = ctx.accounts.config_timelock_account.delay;
+    if old_delay != new_delay {
+        emit!(ConfigValueChanged {
+            field: ConfigField::ConfigDelay,
+            old_value: old_delay,
+            new_value: new_delay,
+        });
+    }
+
+    emit!(ConfigChangeExecuted {
+        change_id: pending_config_change.change_id,
+        change: pending_config_change.change,
+    });
+
+    Ok(())
+}
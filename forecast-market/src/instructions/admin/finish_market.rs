@@ -2,17 +2,22 @@ use std::ops::DerefMut;
 
 use anchor_lang::prelude::*;
 
-use crate::{error::ProgramErrorCode, ConfigAccount, MarketAccount, MarketStatus};
+use crate::{error::ProgramErrorCode, ConfigAccount, MarketAccount, MarketStatus, Role, Roles, ROLES_SEED};
 
 #[derive(Accounts)]
 pub struct FinishMarket<'info> {
     #[account(
         mut,
-        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+        constraint = market_account.is_operator(&owner.key()) || roles_account.has_role(&owner.key(), Role::Resolver, &config_account.owner) @ ProgramErrorCode::Unauthorized
     )]
     pub owner: Signer<'info>,
     #[account(mut)]
     pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
     #[account(
       mut,
       constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved
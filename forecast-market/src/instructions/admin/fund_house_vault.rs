@@ -0,0 +1,105 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_user_to_pool_vault;
+use crate::{error::ProgramErrorCode, ConfigAccount, MarketAccount, MarketStatus, MarketType, Role, Roles, ROLES_SEED};
+
+#[derive(Accounts)]
+pub struct FundHouseVault<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::Treasurer, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = bet_mint,
+        associated_token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Draft || market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved,
+      constraint = market_account.market_type != MarketType::Lmsr @ ProgramErrorCode::InvalidMarketType,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct HouseVaultFunded {
+    pub market_key: u64,
+    pub amount: u64,
+    pub house_tokens: u64,
+}
+
+/// Funding a draft parimutuel market turns it into a fixed-odds market.
+pub fn fund_house_vault(ctx: Context<FundHouseVault>, amount: u64) -> Result<()> {
+    let vault_amount = ctx.accounts.vault_token_account.amount;
+
+    transfer_from_user_to_pool_vault(
+        &ctx.accounts.owner_token_account.to_account_info(),
+        &ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        amount,
+    )?;
+
+    // The house backs payouts with what the vault got, net of any transfer fee.
+    ctx.accounts.vault_token_account.reload()?;
+    let received = ctx
+        .accounts
+        .vault_token_account
+        .amount
+        .checked_sub(vault_amount)
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    let market_account = ctx.accounts.market_account.deref_mut();
+
+    if market_account.market_type == MarketType::Parimutuel {
+        require!(
+            market_account.status == MarketStatus::Draft && market_account.market_total_tokens == 0,
+            ProgramErrorCode::InvalidMarketType
+        );
+        market_account.market_type = MarketType::FixedOdds;
+    }
+
+    market_account.house_tokens = market_account
+        .house_tokens
+        .checked_add(received)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    emit!(HouseVaultFunded {
+        market_key: market_account.market_key,
+        amount: received,
+        house_tokens: market_account.house_tokens,
+    });
+
+    Ok(())
+}
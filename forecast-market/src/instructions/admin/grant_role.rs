@@ -0,0 +1,59 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, Role, RoleGrant, Roles, CONFIG_SEED, MAX_ROLE_GRANTS,
+    ROLES_SEED,
+};
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&admin.key(), Role::Admin, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = Roles::MAX_SIZE,
+        seeds = [ROLES_SEED.as_bytes()],
+        bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub key: Pubkey,
+    pub role: Role,
+}
+
+pub fn grant_role(ctx: Context<GrantRole>, key: Pubkey, role: Role) -> Result<()> {
+    let roles_account = ctx.accounts.roles_account.deref_mut();
+
+    roles_account.bump = ctx.bumps.roles_account;
+
+    if !roles_account
+        .grants
+        .iter()
+        .any(|grant| grant.key == key && grant.role == role)
+    {
+        require!(
+            roles_account.grants.len() < MAX_ROLE_GRANTS,
+            ProgramErrorCode::TooManyRoleGrants
+        );
+        roles_account.grants.push(RoleGrant { key, role });
+    }
+
+    emit!(RoleGranted { key, role });
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use anchor_lang::prelude::*;
 
 use crate::{
     error::ProgramErrorCode,
-    states::{ConfigAccount, CONFIG_SEED},
+    states::{ConfigAccount, ConfigField, ConfigTimelockAccount, CONFIG_SEED, CONFIG_TIMELOCK_SEED},
 };
 
 #[derive(Accounts)]
@@ -19,10 +19,63 @@ pub struct Initialize<'info> {
         bump
     )]
     pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ConfigTimelockAccount::LEN,
+        seeds = [CONFIG_TIMELOCK_SEED.as_bytes()],
+        bump
+    )]
+    pub config_timelock_account: Account<'info, ConfigTimelockAccount>,
 
     pub system_program: Program<'info, System>,
 }
 
+#[event]
+pub struct ConfigKeyChanged {
+    pub field: ConfigField,
+    pub old_value: Pubkey,
+    pub new_value: Pubkey,
+}
+
+#[event]
+pub struct ConfigValueChanged {
+    pub field: ConfigField,
+    pub old_value: u64,
+    pub new_value: u64,
+}
+
+/// Emit a change event for every config field that differs between `before`
+/// and `after`, so the config history can be rebuilt from events alone.
+pub(crate) fn emit_config_changes(before: &ConfigAccount, after: &ConfigAccount) {
+    for (field, old_value, new_value) in [
+        (ConfigField::Owner, before.owner, after.owner),
+        (ConfigField::RewardMint, before.reward_mint, after.reward_mint),
+        (
+            ConfigField::ServiceFeeAccount,
+            before.service_fee_account,
+            after.service_fee_account,
+        ),
+        (ConfigField::RemainAccount, before.remain_account, after.remain_account),
+    ] {
+        if old_value != new_value {
+            emit!(ConfigKeyChanged {
+                field,
+                old_value,
+                new_value,
+            });
+        }
+    }
+
+    if before.reward_apr != after.reward_apr {
+        emit!(ConfigValueChanged {
+            field: ConfigField::RewardApr,
+            old_value: before.reward_apr,
+            new_value: after.reward_apr,
+        });
+    }
+}
+
 pub fn initialize(ctx: Context<Initialize>, reward_mint: Pubkey, reward_apr: u64) -> Result<()> {
     let config_account = ctx.accounts.config_account.deref_mut();
 
@@ -30,11 +83,20 @@ pub fn initialize(ctx: Context<Initialize>, reward_mint: Pubkey, reward_apr: u64
         return Err(ProgramErrorCode::AlreadyInitialized.into());
     }
 
+    let before = config_account.clone();
+
+    config_account.version = ConfigAccount::VERSION;
     config_account.bump = ctx.bumps.config_account;
     config_account.is_initialized = true;
     config_account.reward_mint = reward_mint;
     config_account.owner = ctx.accounts.owner.key();
     config_account.reward_apr = reward_apr;
 
+    emit_config_changes(&before, config_account);
+
+    ctx.accounts
+        .config_timelock_account
+        .init(ctx.bumps.config_timelock_account);
+
     Ok(())
 }
@@ -1,11 +1,23 @@
 pub mod initialize;
 pub use initialize::*;
 
-pub mod set_account;
-pub use set_account::*;
+pub mod schedule_config_change;
+pub use schedule_config_change::*;
 
-pub mod update_owner;
-pub use update_owner::*;
+pub mod execute_config_change;
+pub use execute_config_change::*;
+
+pub mod cancel_config_change;
+pub use cancel_config_change::*;
+
+pub mod propose_owner;
+pub use propose_owner::*;
+
+pub mod accept_owner;
+pub use accept_owner::*;
+
+pub mod cancel_owner_transfer;
+pub use cancel_owner_transfer::*;
 
 pub mod draft_market;
 pub use draft_market::*;
@@ -28,5 +40,53 @@ pub use finish_market::*;
 pub mod retrieve_token;
 pub use retrieve_token::*;
 
-pub mod update_reward_config;
-pub use update_reward_config::*;
+pub mod create_outcome_mint;
+pub use create_outcome_mint::*;
+
+pub mod set_answer_odds;
+pub use set_answer_odds::*;
+
+pub mod fund_house_vault;
+pub use fund_house_vault::*;
+
+pub mod withdraw_house_tokens;
+pub use withdraw_house_tokens::*;
+
+pub mod set_market_vesting;
+pub use set_market_vesting::*;
+
+pub mod set_fee_schedule;
+pub use set_fee_schedule::*;
+
+pub mod set_creator_fee_override;
+pub use set_creator_fee_override::*;
+
+pub mod set_revenue_split;
+pub use set_revenue_split::*;
+
+pub mod set_market_revenue_splits;
+pub use set_market_revenue_splits::*;
+
+pub mod set_fee_discount_tiers;
+pub use set_fee_discount_tiers::*;
+
+pub mod grant_role;
+pub use grant_role::*;
+
+pub mod revoke_role;
+pub use revoke_role::*;
+
+pub mod set_resolver_committee;
+pub use set_resolver_committee::*;
+
+pub mod set_market_committee;
+pub use set_market_committee::*;
+
+pub mod set_oracle_config;
+pub use set_oracle_config::*;
+
+pub mod rule_dispute;
+pub use rule_dispute::*;
+
+pub mod set_market_operator;
+pub use set_market_operator::*;
@@ -0,0 +1,61 @@
+use std::ops::DerefMut;
+
+use crate::{
+    error::ProgramErrorCode,
+    states::{ConfigAccount, OwnerTransferAccount, CONFIG_SEED, OWNER_TRANSFER_SEED},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeOwner<'info> {
+    #[account(
+    mut,
+    constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = OwnerTransferAccount::LEN,
+        seeds = [OWNER_TRANSFER_SEED.as_bytes()],
+        bump,
+    )]
+    pub owner_transfer_account: Account<'info, OwnerTransferAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct OwnerTransferProposed {
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+    pub executable_time: u64,
+}
+
+/// Propose `new_owner`, who can accept after `delay` seconds. Proposing again
+/// replaces the pending proposal.
+pub fn propose_owner(ctx: Context<ProposeOwner>, new_owner: Pubkey, delay: u64) -> Result<()> {
+    let owner_transfer_account = ctx.accounts.owner_transfer_account.deref_mut();
+
+    let clock = Clock::get()?;
+
+    owner_transfer_account.bump = ctx.bumps.owner_transfer_account;
+    owner_transfer_account.pending_owner = new_owner;
+    owner_transfer_account.propose_time = clock.unix_timestamp as u64;
+    owner_transfer_account.delay = delay;
+
+    emit!(OwnerTransferProposed {
+        owner: ctx.accounts.owner.key(),
+        pending_owner: new_owner,
+        executable_time: owner_transfer_account.executable_time(),
+    });
+
+    Ok(())
+}
@@ -4,7 +4,8 @@ use anchor_spl::{associated_token::AssociatedToken, token::Token};
 use crate::{
     error::ProgramErrorCode,
     helper::{is_retrieve_available, transfer_from_pool_vault_to_user},
-    ConfigAccount, MarketAccount, MARKET_SEED,
+    ConfigAccount, MarketAccount, MARKET_REWARD_SEED, MARKET_SEED,
+    Role, Roles, ROLES_SEED,
 };
 
 use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
@@ -13,20 +14,47 @@ use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
 pub struct RetrieveTokens<'info> {
     #[account(
         mut,
-        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+        constraint = roles_account.has_role(&owner.key(), Role::Treasurer, &config_account.owner) @ ProgramErrorCode::Unauthorized
     )]
     pub owner: Signer<'info>,
     #[account(mut)]
     pub config_account: Account<'info, ConfigAccount>,
-    #[account(mut)]
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = market_account
+    )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = config_account.remain_account
+    )]
     pub remains_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(mut)]
     pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(mut)]
     pub market_account: Account<'info, MarketAccount>,
+    /// Required when the market has a reward escrow, whose unused budget goes
+    /// back to `vault_reward_token_account`. Vesting rewards stay behind.
+    pub reward_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(
+        mut,
+        seeds = [MARKET_REWARD_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump,
+    )]
+    pub market_reward_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        token::authority = config_account
+    )]
+    pub vault_reward_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
     pub token_program: Program<'info, Token>,
     pub token_2022_program: Program<'info, Token2022>,
 
@@ -44,6 +72,18 @@ pub fn retrieve_tokens(ctx: Context<RetrieveTokens>) -> Result<()> {
 
     let remains_amount = market_account.market_remain_tokens;
 
+    // The sweep may only take the remainder, never the balances reserved for
+    // outcome tokens, the house or the service fee.
+    let reserved_tokens = market_account.reserved_vault_tokens()?;
+    require!(
+        ctx.accounts
+            .vault_token_account
+            .amount
+            .checked_sub(remains_amount)
+            .is_some_and(|left| left >= reserved_tokens),
+        ProgramErrorCode::ReservedTokensShortfall
+    );
+
     let seeds: &[&[u8]] = &[
         MARKET_SEED.as_bytes(),
         &market_account.market_key.to_le_bytes(),
@@ -60,6 +100,35 @@ pub fn retrieve_tokens(ctx: Context<RetrieveTokens>) -> Result<()> {
         remains_amount,
         &[&seeds],
     )?;
+    ctx.accounts.market_account.market_remain_tokens = 0;
+
+    if ctx.accounts.market_account.has_reward_escrow() {
+        match (
+            &ctx.accounts.reward_mint,
+            &ctx.accounts.market_reward_token_account,
+            &ctx.accounts.vault_reward_token_account,
+        ) {
+            (
+                Some(reward_mint),
+                Some(market_reward_token_account),
+                Some(vault_reward_token_account),
+            ) => {
+                transfer_from_pool_vault_to_user(
+                    &market_reward_token_account.to_account_info(),
+                    &vault_reward_token_account.to_account_info(),
+                    reward_mint.clone(),
+                    &ctx.accounts.market_account.to_account_info(),
+                    &ctx.accounts.token_program.to_account_info(),
+                    Some(&ctx.accounts.token_2022_program.to_account_info()),
+                    market_reward_token_account
+                        .amount
+                        .saturating_sub(ctx.accounts.market_account.vesting_reward_tokens),
+                    &[&seeds],
+                )?;
+            }
+            _ => return err!(ProgramErrorCode::InvalidRewardVault),
+        }
+    }
 
     Ok(())
 }
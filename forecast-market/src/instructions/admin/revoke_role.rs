@@ -0,0 +1,42 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{error::ProgramErrorCode, ConfigAccount, Role, Roles, CONFIG_SEED, ROLES_SEED};
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        constraint = roles_account.has_role(&admin.key(), Role::Admin, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        mut,
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub key: Pubkey,
+    pub role: Role,
+}
+
+pub fn revoke_role(ctx: Context<RevokeRole>, key: Pubkey, role: Role) -> Result<()> {
+    let roles_account = ctx.accounts.roles_account.deref_mut();
+
+    roles_account
+        .grants
+        .retain(|grant| !(grant.key == key && grant.role == role));
+
+    emit!(RoleRevoked { key, role });
+
+    Ok(())
+}
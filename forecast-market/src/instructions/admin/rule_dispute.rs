@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::helper::transfer_from_pool_vault_to_user;
+use crate::instructions::admin::sucess_market::*;
+use crate::{
+    error::ProgramErrorCode, MarketStatus, OracleConfig, ProposalStatus, ResolutionProposal,
+    ORACLE_CONFIG_SEED, PROPOSAL_BOND_SEED, RESOLUTION_PROPOSAL_SEED,
+};
+
+#[derive(Accounts)]
+pub struct RuleDispute<'info> {
+    /// `success.owner` must be the arbiter, or the config owner when no
+    /// arbiter is set.
+    pub success: SuccessMarket<'info>,
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED.as_bytes()],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [RESOLUTION_PROPOSAL_SEED.as_bytes(), &success.market_account.market_key.to_le_bytes()],
+        bump = resolution_proposal.bump,
+        constraint = resolution_proposal.status == ProposalStatus::Disputed @ ProgramErrorCode::InvalidProposalStatus,
+    )]
+    pub resolution_proposal: Box<Account<'info, ResolutionProposal>>,
+    /// CHECK: checked against `resolution_proposal.proposer`, receives its rent.
+    #[account(
+        mut,
+        address = resolution_proposal.proposer @ ProgramErrorCode::Unauthorized
+    )]
+    pub proposer: UncheckedAccount<'info>,
+    /// Token account of the side the ruling agrees with.
+    #[account(
+        mut,
+        token::mint = success.bet_mint
+    )]
+    pub winner_bet_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [PROPOSAL_BOND_SEED.as_bytes(), &success.market_account.market_key.to_le_bytes()],
+        bump,
+    )]
+    pub bond_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+#[event]
+pub struct DisputeRuled {
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub winner: Pubkey,
+    pub slashed_bond: u64,
+}
+
+/// Final ruling on a disputed proposal. The market resolves to
+/// `correct_answer_key` and the side that got it right takes both bonds.
+pub fn rule_dispute<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RuleDispute<'info>>,
+    correct_answer_key: u64,
+) -> Result<()> {
+    let success = &ctx.accounts.success;
+
+    require!(
+        success.owner.key() == ctx.accounts.oracle_config.arbiter_or(&success.config_account.owner),
+        ProgramErrorCode::Unauthorized
+    );
+    require!(
+        success.market_account.status == MarketStatus::Disputed,
+        ProgramErrorCode::InvalidProposalStatus
+    );
+
+    let resolution_proposal = &ctx.accounts.resolution_proposal;
+    let winner = resolution_proposal.winner(correct_answer_key);
+
+    require!(
+        ctx.accounts.winner_bet_token_account.owner == winner,
+        ProgramErrorCode::Unauthorized
+    );
+
+    let market_key = resolution_proposal.market_key;
+    let bond_amount = resolution_proposal.bond_amount;
+    let proposal_bump = resolution_proposal.bump;
+
+    resolve_market(
+        &mut ctx.accounts.success,
+        &ctx.bumps.success,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        correct_answer_key,
+    )?;
+
+    let success = &ctx.accounts.success;
+    let proposal_seeds: &[&[u8]] = &[
+        RESOLUTION_PROPOSAL_SEED.as_bytes(),
+        &market_key.to_le_bytes(),
+        &[proposal_bump],
+    ];
+
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.bond_token_account.to_account_info(),
+        &ctx.accounts.winner_bet_token_account.to_account_info(),
+        success.bet_mint.clone(),
+        &ctx.accounts.resolution_proposal.to_account_info(),
+        &success.token_program.to_account_info(),
+        Some(&success.token_2022_program.to_account_info()),
+        bond_amount
+            .checked_mul(2)
+            .ok_or(ProgramErrorCode::Overflow)?,
+        &[&proposal_seeds],
+    )?;
+
+    emit!(DisputeRuled {
+        market_key,
+        answer_key: correct_answer_key,
+        winner,
+        slashed_bond: bond_amount,
+    });
+
+    Ok(())
+}
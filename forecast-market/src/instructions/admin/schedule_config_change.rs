@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::MIN_CONFIG_CHANGE_DELAY, error::ProgramErrorCode, ConfigAccount, ConfigChange,
+    ConfigTimelockAccount, PendingConfigChange, Roles, CONFIG_SEED, CONFIG_TIMELOCK_SEED,
+    PENDING_CONFIG_CHANGE_SEED, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+pub struct ScheduleConfigChange<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ConfigTimelockAccount::LEN,
+        seeds = [CONFIG_TIMELOCK_SEED.as_bytes()],
+        bump,
+    )]
+    pub config_timelock_account: Account<'info, ConfigTimelockAccount>,
+    #[account(
+        init,
+        payer = owner,
+        space = PendingConfigChange::MAX_SIZE,
+        seeds = [PENDING_CONFIG_CHANGE_SEED.as_bytes(), &config_timelock_account.next_change_id.to_le_bytes()],
+        bump,
+    )]
+    pub pending_config_change: Account<'info, PendingConfigChange>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ConfigChangeScheduled {
+    pub change_id: u64,
+    pub proposer: Pubkey,
+    pub change: ConfigChange,
+    pub executable_time: u64,
+}
+
+/// Queue a config change. It can be executed once the timelock delay has
+/// passed, and cancelled until then.
+pub fn schedule_config_change(ctx: Context<ScheduleConfigChange>, change: ConfigChange) -> Result<()> {
+    let proposer = ctx.accounts.owner.key();
+    require!(
+        ctx.accounts.roles_account.has_role(
+            &proposer,
+            change.role(),
+            &ctx.accounts.config_account.owner
+        ),
+        ProgramErrorCode::Unauthorized
+    );
+
+    if let ConfigChange::ConfigDelay { delay } = change {
+        require!(
+            delay >= MIN_CONFIG_CHANGE_DELAY,
+            ProgramErrorCode::ConfigDelayTooShort
+        );
+    }
+
+    let clock = Clock::get()?;
+
+    let config_timelock_account = &mut ctx.accounts.config_timelock_account;
+    config_timelock_account.init(ctx.bumps.config_timelock_account);
+
+    let change_id = config_timelock_account.next_change_id;
+    config_timelock_account.next_change_id += 1;
+
+    let executable_time = (clock.unix_timestamp as u64)
+        .checked_add(config_timelock_account.delay)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let pending_config_change = &mut ctx.accounts.pending_config_change;
+    pending_config_change.bump = ctx.bumps.pending_config_change;
+    pending_config_change.change_id = change_id;
+    pending_config_change.proposer = proposer;
+    pending_config_change.change = change;
+    pending_config_change.executable_time = executable_time;
+
+    emit!(ConfigChangeScheduled {
+        change_id,
+        proposer,
+        change,
+        executable_time,
+    });
+
+    Ok(())
+}
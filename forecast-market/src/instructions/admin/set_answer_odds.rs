@@ -0,0 +1,84 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::ODDS_BASIS_POINTS, error::ProgramErrorCode, AnswerAccount, AnswerOdds,
+    ConfigAccount, MarketAccount, MarketStatus, MarketType, ANSWER_SEED,
+    Role, Roles, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SetAnswerOdds<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::MarketOperator, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+      constraint = market_account.market_type == MarketType::FixedOdds @ ProgramErrorCode::InvalidMarketType,
+      constraint = market_account.status == MarketStatus::Draft || market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved,
+    )]
+    pub market_account: Account<'info, MarketAccount>,
+    #[account(
+      mut,
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+      realloc = AnswerAccount::MAX_SIZE,
+      realloc::payer = owner,
+      realloc::zero = false,
+    )]
+    pub answer_account: Account<'info, AnswerAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct AnswerOddsSet {
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub odds: u64,
+}
+
+/// New odds only apply to bets placed afterwards; existing bets keep the
+/// payout they locked in.
+pub fn set_answer_odds(ctx: Context<SetAnswerOdds>, answer_key: u64, odds: u64) -> Result<()> {
+    require!(odds > ODDS_BASIS_POINTS, ProgramErrorCode::InvalidOdds);
+
+    let answer_account = ctx.accounts.answer_account.deref_mut();
+
+    if !answer_account
+        .answers
+        .iter()
+        .any(|answer| answer.answer_key == answer_key)
+    {
+        return Err(ProgramErrorCode::AnswerNotExists.into());
+    }
+
+    match answer_account
+        .odds
+        .iter_mut()
+        .find(|answer_odds| answer_odds.answer_key == answer_key)
+    {
+        Some(answer_odds) => answer_odds.odds = odds,
+        None => answer_account.odds.push(AnswerOdds {
+            answer_key,
+            odds,
+            liability: 0,
+        }),
+    }
+
+    emit!(AnswerOddsSet {
+        market_key: ctx.accounts.market_account.market_key,
+        answer_key,
+        odds,
+    });
+
+    Ok(())
+}
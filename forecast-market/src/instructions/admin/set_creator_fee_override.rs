@@ -0,0 +1,86 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, CreatorFeeOverride, FeePercentages, FeeSchedule,
+    CONFIG_SEED, FEE_SCHEDULE_SEED, MAX_CREATOR_FEE_OVERRIDES,
+    Role, Roles, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SetCreatorFeeOverride<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::Treasurer, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+        mut,
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+}
+
+#[event]
+pub struct CreatorFeeOverrideSet {
+    pub creator: Pubkey,
+    pub creator_fee_percentage: u64,
+    pub service_fee_percentage: u64,
+}
+
+#[event]
+pub struct CreatorFeeOverrideRemoved {
+    pub creator: Pubkey,
+}
+
+/// Set the fees `draft_market` uses for `creator`, or drop its override when
+/// `fees` is `None`.
+pub fn set_creator_fee_override(
+    ctx: Context<SetCreatorFeeOverride>,
+    creator: Pubkey,
+    fees: Option<FeePercentages>,
+) -> Result<()> {
+    let fee_schedule = ctx.accounts.fee_schedule.deref_mut();
+
+    fee_schedule.overrides.retain(|o| o.creator != creator);
+
+    match fees {
+        Some(FeePercentages {
+            creator_fee_percentage,
+            service_fee_percentage,
+        }) => {
+            fee_schedule.validate(creator_fee_percentage, service_fee_percentage)?;
+            require!(
+                fee_schedule.overrides.len() < MAX_CREATOR_FEE_OVERRIDES,
+                ProgramErrorCode::TooManyFeeOverrides
+            );
+
+            fee_schedule.overrides.push(CreatorFeeOverride {
+                creator,
+                creator_fee_percentage,
+                service_fee_percentage,
+            });
+
+            emit!(CreatorFeeOverrideSet {
+                creator,
+                creator_fee_percentage,
+                service_fee_percentage,
+            });
+        }
+        None => emit!(CreatorFeeOverrideRemoved { creator }),
+    }
+
+    Ok(())
+}
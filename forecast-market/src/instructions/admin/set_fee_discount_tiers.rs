@@ -0,0 +1,58 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, FeeDiscountTier, FeeDiscountTiers, CONFIG_SEED,
+    FEE_DISCOUNT_SEED,
+    Role, Roles, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SetFeeDiscountTiers<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::Treasurer, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = FeeDiscountTiers::MAX_SIZE,
+        seeds = [FEE_DISCOUNT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_discount_tiers: Account<'info, FeeDiscountTiers>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct FeeDiscountTiersSet {
+    pub tiers: Vec<FeeDiscountTier>,
+}
+
+pub fn set_fee_discount_tiers(
+    ctx: Context<SetFeeDiscountTiers>,
+    tiers: Vec<FeeDiscountTier>,
+) -> Result<()> {
+    FeeDiscountTiers::validate(&tiers)?;
+
+    let fee_discount_tiers = ctx.accounts.fee_discount_tiers.deref_mut();
+
+    fee_discount_tiers.bump = ctx.bumps.fee_discount_tiers;
+    fee_discount_tiers.tiers = tiers.clone();
+
+    emit!(FeeDiscountTiersSet { tiers });
+
+    Ok(())
+}
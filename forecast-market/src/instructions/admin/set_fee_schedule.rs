@@ -0,0 +1,72 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::BASIS_POINTS, error::ProgramErrorCode, ConfigAccount, FeeSchedule, CONFIG_SEED,
+    FEE_SCHEDULE_SEED,
+    Role, Roles, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SetFeeSchedule<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::Treasurer, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = FeeSchedule::MAX_SIZE,
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct FeeScheduleSet {
+    pub default_creator_fee_percentage: u64,
+    pub default_service_fee_percentage: u64,
+    pub max_total_fee_percentage: u64,
+}
+
+pub fn set_fee_schedule(
+    ctx: Context<SetFeeSchedule>,
+    default_creator_fee_percentage: u64,
+    default_service_fee_percentage: u64,
+    max_total_fee_percentage: u64,
+) -> Result<()> {
+    require!(
+        max_total_fee_percentage <= BASIS_POINTS as u64,
+        ProgramErrorCode::InvalidFeePercentage
+    );
+
+    let fee_schedule = ctx.accounts.fee_schedule.deref_mut();
+
+    fee_schedule.bump = ctx.bumps.fee_schedule;
+    fee_schedule.max_total_fee_percentage = max_total_fee_percentage;
+    fee_schedule.validate(default_creator_fee_percentage, default_service_fee_percentage)?;
+    fee_schedule.default_creator_fee_percentage = default_creator_fee_percentage;
+    fee_schedule.default_service_fee_percentage = default_service_fee_percentage;
+
+    emit!(FeeScheduleSet {
+        default_creator_fee_percentage,
+        default_service_fee_percentage,
+        max_total_fee_percentage,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, MarketAccount, MarketStatus, ResolverCommittee, Role,
+    Roles, CONFIG_SEED, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SetMarketCommittee<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::Admin, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Draft || market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved,
+    )]
+    pub market_account: Account<'info, MarketAccount>,
+    pub resolver_committee: Option<Account<'info, ResolverCommittee>>,
+}
+
+#[event]
+pub struct MarketCommitteeSet {
+    pub market_key: u64,
+    pub resolver_committee: Pubkey,
+}
+
+/// Have the market resolved by `resolver_committee` attestations instead of
+/// a single resolver. Leaving the committee out detaches it.
+pub fn set_market_committee(ctx: Context<SetMarketCommittee>) -> Result<()> {
+    let resolver_committee = ctx
+        .accounts
+        .resolver_committee
+        .as_ref()
+        .map(|committee| committee.key())
+        .unwrap_or_default();
+
+    let market_account: &mut MarketAccount = ctx.accounts.market_account.deref_mut();
+
+    market_account.resolver_committee = resolver_committee;
+
+    emit!(MarketCommitteeSet {
+        market_key: market_account.market_key,
+        resolver_committee,
+    });
+
+    Ok(())
+}
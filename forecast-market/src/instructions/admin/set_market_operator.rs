@@ -0,0 +1,53 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, MarketAccount, Role, Roles, CONFIG_SEED, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SetMarketOperator<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::MarketOperator, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(mut)]
+    pub market_account: Account<'info, MarketAccount>,
+}
+
+#[event]
+pub struct MarketOperatorSet {
+    pub market_key: u64,
+    pub previous_operator: Pubkey,
+    pub operator: Pubkey,
+}
+
+/// Let `operator` add answers to, finish, adjourn and resolve this market
+/// only. The protocol roles keep those rights, and `None` removes the
+/// operator.
+pub fn set_market_operator(ctx: Context<SetMarketOperator>, operator: Option<Pubkey>) -> Result<()> {
+    let market_account = ctx.accounts.market_account.deref_mut();
+
+    let previous_operator = market_account.operator;
+    market_account.operator = operator.unwrap_or_default();
+
+    emit!(MarketOperatorSet {
+        market_key: market_account.market_key,
+        previous_operator,
+        operator: market_account.operator,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, MarketAccount, MarketStatus, RevenueSplit, CONFIG_SEED,
+    Role, Roles, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SetMarketRevenueSplits<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::Treasurer, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+      mut,
+      constraint = market_account.status != MarketStatus::Success && market_account.status != MarketStatus::Adjourn @ ProgramErrorCode::MarketNotFinished,
+    )]
+    pub market_account: Account<'info, MarketAccount>,
+    pub creator_split: Option<Account<'info, RevenueSplit>>,
+    pub service_split: Option<Account<'info, RevenueSplit>>,
+}
+
+#[event]
+pub struct MarketRevenueSplitsSet {
+    pub market_key: u64,
+    pub creator_split: Pubkey,
+    pub service_split: Pubkey,
+}
+
+/// Share the market's creator and service fees by the given splits. A side
+/// left out goes back to paying `creator` or `service_fee_account` alone.
+pub fn set_market_revenue_splits(ctx: Context<SetMarketRevenueSplits>) -> Result<()> {
+    let creator_split = ctx
+        .accounts
+        .creator_split
+        .as_ref()
+        .map(|split| split.key())
+        .unwrap_or_default();
+    let service_split = ctx
+        .accounts
+        .service_split
+        .as_ref()
+        .map(|split| split.key())
+        .unwrap_or_default();
+
+    let market_account: &mut MarketAccount = ctx.accounts.market_account.deref_mut();
+
+    market_account.creator_split = creator_split;
+    market_account.service_split = service_split;
+
+    emit!(MarketRevenueSplitsSet {
+        market_key: market_account.market_key,
+        creator_split,
+        service_split,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{error::ProgramErrorCode, ConfigAccount, MarketAccount, MarketStatus, Role, Roles, ROLES_SEED};
+
+#[derive(Accounts)]
+pub struct SetMarketVesting<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::MarketOperator, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Draft @ ProgramErrorCode::MarketNotDraft,
+    )]
+    pub market_account: Account<'info, MarketAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct MarketVestingSet {
+    pub market_key: u64,
+    pub vesting_threshold: u64,
+    pub vesting_period: u64,
+}
+
+/// Payouts above `vesting_threshold` and all rewards of the market vest
+/// linearly over `vesting_period` seconds. A zero period turns vesting off.
+/// Terms are fixed once the market is approved and open for bets.
+pub fn set_market_vesting(
+    ctx: Context<SetMarketVesting>,
+    vesting_threshold: u64,
+    vesting_period: u64,
+) -> Result<()> {
+    let market_account: &mut MarketAccount = ctx.accounts.market_account.deref_mut();
+
+    market_account.vesting_threshold = vesting_threshold;
+    market_account.vesting_period = vesting_period;
+
+    emit!(MarketVestingSet {
+        market_key: market_account.market_key,
+        vesting_threshold,
+        vesting_period,
+    });
+
+    Ok(())
+}
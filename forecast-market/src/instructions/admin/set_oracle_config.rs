@@ -0,0 +1,75 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, OracleConfig, Role, Roles, CONFIG_SEED,
+    ORACLE_CONFIG_SEED, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SetOracleConfig<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::Admin, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = OracleConfig::LEN,
+        seeds = [ORACLE_CONFIG_SEED.as_bytes()],
+        bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct OracleConfigSet {
+    pub arbiter: Pubkey,
+    pub bond_basis_points: u16,
+    pub liveness: u64,
+    pub proposal_reward: u64,
+    pub vote_period: u64,
+}
+
+pub fn set_oracle_config(
+    ctx: Context<SetOracleConfig>,
+    arbiter: Option<Pubkey>,
+    bond_basis_points: u16,
+    liveness: u64,
+    proposal_reward: u64,
+    vote_period: u64,
+) -> Result<()> {
+    OracleConfig::validate(bond_basis_points, liveness, vote_period)?;
+
+    let oracle_config = ctx.accounts.oracle_config.deref_mut();
+
+    oracle_config.bump = ctx.bumps.oracle_config;
+    oracle_config.arbiter = arbiter.unwrap_or_default();
+    oracle_config.bond_basis_points = bond_basis_points;
+    oracle_config.liveness = liveness;
+    oracle_config.proposal_reward = proposal_reward;
+    oracle_config.vote_period = vote_period;
+
+    emit!(OracleConfigSet {
+        arbiter: oracle_config.arbiter,
+        bond_basis_points,
+        liveness,
+        proposal_reward,
+        vote_period,
+    });
+
+    Ok(())
+}
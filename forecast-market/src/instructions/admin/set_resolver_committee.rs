@@ -0,0 +1,68 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, ResolverCommittee, Role, Roles, CONFIG_SEED,
+    RESOLVER_COMMITTEE_SEED, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(committee_key: u64)]
+pub struct SetResolverCommittee<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::Admin, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ResolverCommittee::MAX_SIZE,
+        seeds = [RESOLVER_COMMITTEE_SEED.as_bytes(), &committee_key.to_le_bytes()],
+        bump,
+    )]
+    pub resolver_committee: Account<'info, ResolverCommittee>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ResolverCommitteeSet {
+    pub committee_key: u64,
+    pub threshold: u8,
+    pub members: Vec<Pubkey>,
+}
+
+pub fn set_resolver_committee(
+    ctx: Context<SetResolverCommittee>,
+    committee_key: u64,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    ResolverCommittee::validate(&members, threshold)?;
+
+    let resolver_committee = ctx.accounts.resolver_committee.deref_mut();
+
+    resolver_committee.bump = ctx.bumps.resolver_committee;
+    resolver_committee.committee_key = committee_key;
+    resolver_committee.threshold = threshold;
+    resolver_committee.members = members.clone();
+
+    emit!(ResolverCommitteeSet {
+        committee_key,
+        threshold,
+        members,
+    });
+
+    Ok(())
+}
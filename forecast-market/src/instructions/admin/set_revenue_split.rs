@@ -0,0 +1,65 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, RevenueSplit, SplitRecipient, CONFIG_SEED,
+    REVENUE_SPLIT_SEED,
+    Role, Roles, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(split_key: u64)]
+pub struct SetRevenueSplit<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::Treasurer, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = RevenueSplit::MAX_SIZE,
+        seeds = [REVENUE_SPLIT_SEED.as_bytes(), &split_key.to_le_bytes()],
+        bump,
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct RevenueSplitSet {
+    pub split_key: u64,
+    pub recipients: Vec<SplitRecipient>,
+}
+
+pub fn set_revenue_split(
+    ctx: Context<SetRevenueSplit>,
+    split_key: u64,
+    recipients: Vec<SplitRecipient>,
+) -> Result<()> {
+    RevenueSplit::validate(&recipients)?;
+
+    let revenue_split = ctx.accounts.revenue_split.deref_mut();
+
+    revenue_split.bump = ctx.bumps.revenue_split;
+    revenue_split.split_key = split_key;
+    revenue_split.recipients = recipients.clone();
+
+    emit!(RevenueSplitSet {
+        split_key,
+        recipients,
+    });
+
+    Ok(())
+}
@@ -2,46 +2,100 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, Token2022};
 use anchor_spl::{associated_token::AssociatedToken, token::Token};
 
-use crate::helper::transfer_from_pool_vault_to_user;
+use crate::helper::{calculate_market_fees, transfer_from_pool_vault_to_user, MarketFees};
 use crate::{
-    constant::BASIS_POINTS, error::ProgramErrorCode, AnswerAccount, ConfigAccount, MarketAccount,
-    MarketStatus, MARKET_SEED,
+    error::ProgramErrorCode, AnswerAccount, ConfigAccount, FeeLedger, LmsrAccount, MarketAccount,
+    MarketStatus, MarketType, ResolutionVotes, RevenueSplit, ANSWER_SEED, FEE_LEDGER_SEED, FEE_TREASURY_SEED,
+    LMSR_SEED, MARKET_SEED, RESOLUTION_PROPOSAL_SEED, RESOLUTION_VOTES_SEED,
+    Role, Roles, ROLES_SEED,
 };
 
 #[derive(Accounts)]
 pub struct SuccessMarket<'info> {
-    #[account(
-        mut,
-        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
-    )]
+    /// A resolver or the market's operator, or anyone once the market's
+    /// committee has agreed.
+    #[account(mut)]
     pub owner: Signer<'info>,
     #[account(mut)]
     pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
     #[account(mut)]
     pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
-        mut,
+        init_if_needed,
+        payer = owner,
+        seeds = [FEE_TREASURY_SEED.as_bytes(), bet_mint.key().as_ref()],
+        bump,
         token::mint = bet_mint,
-        token::authority = market_account.creator
+        token::authority = config_account
     )]
-    pub creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub fee_treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
-        mut,
-        token::mint = bet_mint,
-        token::authority = config_account.service_fee_account
+        init_if_needed,
+        payer = owner,
+        space = FeeLedger::MAX_SIZE,
+        seeds = [FEE_LEDGER_SEED.as_bytes(), market_account.creator.as_ref(), bet_mint.key().as_ref()],
+        bump,
     )]
-    pub service_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub creator_fee_ledger: Box<Account<'info, FeeLedger>>,
+    /// Left out when the creator is also the service fee account, as both
+    /// ledgers would then be the same account.
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = FeeLedger::MAX_SIZE,
+        seeds = [FEE_LEDGER_SEED.as_bytes(), config_account.service_fee_account.as_ref(), bet_mint.key().as_ref()],
+        bump,
+    )]
+    pub service_fee_ledger: Option<Box<Account<'info, FeeLedger>>>,
     #[account(
       mut,
-      constraint = market_account.status == MarketStatus::Finished @ ProgramErrorCode::MarketNotFinished,
+      constraint = market_account.status == MarketStatus::Finished || market_account.status == MarketStatus::Disputed @ ProgramErrorCode::MarketNotFinished,
       constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint
     )]
     pub market_account: Account<'info, MarketAccount>,
-    #[account(mut)]
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = market_account
+    )]
     pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(mut)]
+    #[account(
+      mut,
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
     pub answer_account: Account<'info, AnswerAccount>,
+    #[account(
+      seeds = [LMSR_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump = lmsr_account.bump,
+    )]
+    pub lmsr_account: Option<Box<Account<'info, LmsrAccount>>>,
+    #[account(
+      constraint = creator_split.key() == market_account.creator_split @ ProgramErrorCode::InvalidRevenueSplit,
+    )]
+    pub creator_split: Option<Box<Account<'info, RevenueSplit>>>,
+    #[account(
+      constraint = service_split.key() == market_account.service_split @ ProgramErrorCode::InvalidRevenueSplit,
+    )]
+    pub service_split: Option<Box<Account<'info, RevenueSplit>>>,
+    #[account(
+      seeds = [RESOLUTION_VOTES_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump = resolution_votes.bump,
+    )]
+    pub resolution_votes: Option<Box<Account<'info, ResolutionVotes>>>,
+    /// CHECK: the market's resolution proposal PDA. `success_market` needs it
+    /// to be empty; open proposals are settled through the oracle instructions.
+    #[account(
+      seeds = [RESOLUTION_PROPOSAL_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub resolution_proposal: UncheckedAccount<'info>,
 
     pub token_program: Program<'info, Token>,
 
@@ -60,55 +114,201 @@ pub struct MarketSuccess {
     pub service_fee: u64,
     pub market_remain_tokens: u64,
 }
-struct MarketFees {
-    creator_fee: u64,
-    service_fee: u64,
-}
+/// The creator fee is taken now. The service fee is charged on each winning
+/// claim instead, so stakers of the reward token can get a discount.
+fn apply_market_fees(market_account: &mut MarketAccount) -> Result<MarketFees> {
+    let fees = calculate_market_fees(market_account, market_account.market_remain_tokens)?;
 
-fn calculate_market_fees(market_account: &mut MarketAccount) -> Result<MarketFees> {
-    let remain_tokens = market_account.market_remain_tokens as u128;
+    // Update market_reward_base_tokens
+    market_account.market_reward_base_tokens = fees
+        .reward_base_tokens
+        .checked_add(fees.service_fee)
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+    market_account.claim_service_fee_percentage = market_account.service_fee_percentage;
 
-    let creator_fee_percentage = market_account.creator_fee_percentage as u128;
-    let service_fee_percentage = market_account.service_fee_percentage as u128;
+    // Update market_remain_tokens
+    market_account.market_remain_tokens = market_account
+        .market_remain_tokens
+        .checked_sub(fees.creator_fee)
+        .ok_or(ProgramErrorCode::MathOperationError)?;
 
-    let old_creator_fee = market_account.creator_fee as u128;
+    Ok(MarketFees {
+        service_fee: 0,
+        ..fees
+    })
+}
 
-    let additional_creator_fee = remain_tokens
-        .checked_mul(creator_fee_percentage)
-        .and_then(|result| result.checked_div(BASIS_POINTS as u128))
-        .unwrap();
+/// LMSR markets pay 1 token per winning share, so only those tokens stay in
+/// the vault and everything above them goes back to the creator.
+fn apply_lmsr_settlement(
+    market_account: &mut MarketAccount,
+    lmsr_account: &LmsrAccount,
+    correct_answer_key: u64,
+) -> Result<MarketFees> {
+    let index = lmsr_account
+        .outcome_index(correct_answer_key)
+        .ok_or(ProgramErrorCode::MarketDoesNotContainAnswerKey)?;
+    let winning_shares = lmsr_account.outcomes[index].total_shares;
 
-    let creator_fee = old_creator_fee.checked_add(additional_creator_fee).unwrap();
+    let creator_fee = market_account
+        .market_remain_tokens
+        .checked_sub(winning_shares)
+        .ok_or(ProgramErrorCode::MathOperationError)?;
 
-    let service_fee = remain_tokens
-        .checked_mul(service_fee_percentage)
-        .and_then(|result| result.checked_div(BASIS_POINTS as u128))
-        .unwrap();
+    market_account.market_reward_base_tokens = winning_shares;
+    market_account.market_remain_tokens = winning_shares;
 
-    let remaining_tokens = remain_tokens
-        .checked_sub(creator_fee)
-        .and_then(|result| result.checked_sub(service_fee))
-        .unwrap();
+    Ok(MarketFees {
+        creator_fee,
+        service_fee: 0,
+        reward_base_tokens: winning_shares,
+    })
+}
 
-    // Update market_reward_base_tokens
-    market_account.market_reward_base_tokens = remaining_tokens as u64;
+/// Fixed-odds winners are owed the liability locked on the winning answer.
+/// Whatever the stakes and the house funds exceed it by goes back to the house.
+fn apply_fixed_odds_settlement(
+    market_account: &mut MarketAccount,
+    answer_account: &AnswerAccount,
+    correct_answer_key: u64,
+) -> Result<MarketFees> {
+    let winning_liability = answer_account
+        .odds
+        .iter()
+        .find(|answer_odds| answer_odds.answer_key == correct_answer_key)
+        .map(|answer_odds| answer_odds.liability)
+        .unwrap_or(0);
 
-    // Update market_remain_tokens
-    market_account.market_remain_tokens = market_account
+    let available_tokens = market_account
         .market_remain_tokens
-        .checked_sub(creator_fee as u64)
-        .unwrap();
+        .checked_add(market_account.house_tokens)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    market_account.house_tokens = available_tokens
+        .checked_sub(winning_liability)
+        .ok_or(ProgramErrorCode::LiabilityExceedsHouse)?;
+    market_account.market_reward_base_tokens = winning_liability;
+    market_account.market_remain_tokens = winning_liability;
 
     Ok(MarketFees {
-        creator_fee: creator_fee as u64,
-        service_fee: service_fee as u64,
+        creator_fee: 0,
+        service_fee: 0,
+        reward_base_tokens: winning_liability,
     })
 }
 
-pub fn success_market(ctx: Context<SuccessMarket>, correct_answer_key: u64) -> Result<()> {
+/// Credit each split recipient's share of `amount` to its fee ledger, taken
+/// in order from `fee_ledgers`. Recipients that already have their ledger in
+/// `known_fee_ledgers` (the creator and service ledgers) are credited there
+/// and take no entry in `fee_ledgers`.
+pub(crate) fn credit_revenue_split<'info>(
+    revenue_split: &RevenueSplit,
+    amount: u64,
+    fee_ledgers: &mut std::slice::Iter<'info, AccountInfo<'info>>,
+    known_fee_ledgers: &mut [&mut FeeLedger],
+    mint: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<()> {
+    for (recipient, share) in revenue_split.shares(amount) {
+        if let Some(known_fee_ledger) = known_fee_ledgers
+            .iter_mut()
+            .find(|fee_ledger| fee_ledger.recipient == recipient)
+        {
+            known_fee_ledger.credit(share)?;
+            continue;
+        }
+
+        let fee_ledger_info = fee_ledgers
+            .next()
+            .ok_or(ProgramErrorCode::InvalidFeeLedger)?;
+        let mut fee_ledger = Account::<FeeLedger>::try_from(fee_ledger_info)?;
+        require!(
+            fee_ledger.recipient == recipient && fee_ledger.mint == *mint,
+            ProgramErrorCode::InvalidFeeLedger
+        );
+
+        fee_ledger.credit(share)?;
+        fee_ledger.exit(program_id)?;
+    }
+
+    Ok(())
+}
+
+/// Markets with a committee resolve to the answer its members agreed on.
+/// Other markets need a resolver or their operator, and disputed ones
+/// awaiting a final ruling need a resolver. A market with an open proposal
+/// is left to the oracle instructions, which hold its bonds.
+fn authorize_resolution(ctx: &Context<SuccessMarket>, correct_answer_key: u64) -> Result<()> {
+    let market_account = &ctx.accounts.market_account;
+    let signer = ctx.accounts.owner.key();
+
+    require!(
+        ctx.accounts.resolution_proposal.data_is_empty(),
+        ProgramErrorCode::ProposalPending
+    );
+
+    if market_account.status == MarketStatus::Disputed {
+        require!(
+            ctx.accounts.roles_account.has_role(
+                &signer,
+                Role::Resolver,
+                &ctx.accounts.config_account.owner
+            ),
+            ProgramErrorCode::Unauthorized
+        );
+    } else if market_account.resolver_committee == Pubkey::default() {
+        require!(
+            market_account.is_operator(&signer)
+                || ctx.accounts.roles_account.has_role(
+                    &signer,
+                    Role::Resolver,
+                    &ctx.accounts.config_account.owner
+                ),
+            ProgramErrorCode::Unauthorized
+        );
+    } else {
+        let resolution_votes = ctx
+            .accounts
+            .resolution_votes
+            .as_ref()
+            .ok_or(ProgramErrorCode::ResolutionNotAgreed)?;
+        require!(
+            resolution_votes.committee == market_account.resolver_committee
+                && resolution_votes.agreed_answer_key == Some(correct_answer_key),
+            ProgramErrorCode::ResolutionNotAgreed
+        );
+    }
+
+    Ok(())
+}
+
+pub fn success_market<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SuccessMarket<'info>>,
+    correct_answer_key: u64,
+) -> Result<()> {
+    authorize_resolution(&ctx, correct_answer_key)?;
+
+    resolve_market(
+        ctx.accounts,
+        &ctx.bumps,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        correct_answer_key,
+    )
+}
+
+/// Settle the market on `correct_answer_key` and take its fees. Callers
+/// authorize the resolution first.
+pub(crate) fn resolve_market<'info>(
+    accounts: &mut SuccessMarket<'info>,
+    bumps: &SuccessMarketBumps,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    program_id: &Pubkey,
+    correct_answer_key: u64,
+) -> Result<()> {
     let fees: MarketFees = {
-        let market_account = &mut ctx.accounts.market_account;
-        let answer_account = &ctx.accounts.answer_account;
+        let market_account = &mut accounts.market_account;
+        let answer_account = &accounts.answer_account;
 
         if !answer_account
             .answers
@@ -124,43 +324,134 @@ pub fn success_market(ctx: Context<SuccessMarket>, correct_answer_key: u64) -> R
         market_account.correct_answer_key = correct_answer_key;
         market_account.success_time = clock.unix_timestamp as u64;
 
-        calculate_market_fees(market_account)?
+        match market_account.market_type {
+            MarketType::Parimutuel => apply_market_fees(market_account)?,
+            MarketType::Lmsr => {
+                let lmsr_account = accounts
+                    .lmsr_account
+                    .as_ref()
+                    .ok_or(ProgramErrorCode::InvalidMarketType)?;
+                apply_lmsr_settlement(market_account, lmsr_account, correct_answer_key)?
+            }
+            MarketType::FixedOdds => {
+                apply_fixed_odds_settlement(market_account, answer_account, correct_answer_key)?
+            }
+        }
+    };
+
+    let total_fee = fees
+        .creator_fee
+        .checked_add(fees.service_fee)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    // Ledgers are credited what reaches the treasury, net of any transfer
+    // fee, which the creator and service sides bear pro rata.
+    let received_fee = if total_fee > 0 {
+        let seeds: &[&[u8]] = &[
+            MARKET_SEED.as_bytes(),
+            &accounts.market_account.market_key.to_le_bytes(),
+            &[accounts.market_account.bump],
+        ];
+
+        let treasury_amount = accounts.fee_treasury_token_account.amount;
+        transfer_from_pool_vault_to_user(
+            &accounts.vault_token_account.to_account_info(),
+            &accounts.fee_treasury_token_account.to_account_info(),
+            accounts.bet_mint.clone(),
+            &accounts.market_account.to_account_info(),
+            &accounts.token_program.to_account_info(),
+            Some(&accounts.token_2022_program.to_account_info()),
+            total_fee,
+            &[&seeds],
+        )?;
+        accounts.fee_treasury_token_account.reload()?;
+        accounts
+            .fee_treasury_token_account
+            .amount
+            .checked_sub(treasury_amount)
+            .ok_or(ProgramErrorCode::MathOperationError)?
+    } else {
+        0
     };
+    let creator_fee = ((fees.creator_fee as u128 * received_fee as u128)
+        .checked_div(total_fee as u128)
+        .unwrap_or(0)) as u64;
+    let service_fee = received_fee - creator_fee;
+
+    // Fees are credited to the recipients' ledgers and pulled later with
+    // `withdraw_fees`, so a bad payee account cannot block resolution.
+    // Split recipients' ledgers come in `remaining_accounts`, creator side
+    // first, in the order of each split, skipping the creator and service.
+    let bet_mint = accounts.bet_mint.key();
+    let mut split_fee_ledgers = remaining_accounts.iter();
+
+    let shared_fee_ledger =
+        accounts.market_account.creator == accounts.config_account.service_fee_account;
+    require!(
+        accounts.service_fee_ledger.is_some() != shared_fee_ledger,
+        ProgramErrorCode::InvalidFeeLedger
+    );
 
-    let seeds: &[&[u8]] = &[
-        MARKET_SEED.as_bytes(),
-        &ctx.accounts.market_account.market_key.to_le_bytes(),
-        &[ctx.accounts.market_account.bump],
-    ];
-
-    transfer_from_pool_vault_to_user(
-        &ctx.accounts.vault_token_account.to_account_info(),
-        &ctx.accounts.creator_token_account.to_account_info(),
-        ctx.accounts.bet_mint.clone(),
-        &ctx.accounts.market_account.to_account_info(),
-        &ctx.accounts.token_program.to_account_info(),
-        Some(&ctx.accounts.token_2022_program.to_account_info()),
-        fees.creator_fee,
-        &[&seeds],
-    )?;
-
-    transfer_from_pool_vault_to_user(
-        &ctx.accounts.vault_token_account.to_account_info(),
-        &ctx.accounts.service_token_account.to_account_info(),
-        ctx.accounts.bet_mint.clone(),
-        &ctx.accounts.market_account.to_account_info(),
-        &ctx.accounts.token_program.to_account_info(),
-        Some(&ctx.accounts.token_2022_program.to_account_info()),
-        fees.service_fee,
-        &[&seeds],
-    )?;
+    accounts.creator_fee_ledger.init(
+        bumps.creator_fee_ledger,
+        accounts.market_account.creator,
+        bet_mint,
+    );
+    let creator_fee_ledger: &mut FeeLedger = &mut accounts.creator_fee_ledger;
+    let mut fee_ledgers = vec![creator_fee_ledger];
+    if let Some(service_fee_ledger) = accounts.service_fee_ledger.as_mut() {
+        service_fee_ledger.init(
+            bumps.service_fee_ledger.ok_or(ProgramErrorCode::InvalidFeeLedger)?,
+            accounts.config_account.service_fee_account,
+            bet_mint,
+        );
+        fee_ledgers.push(service_fee_ledger);
+    }
+
+    match &accounts.creator_split {
+        Some(creator_split) => credit_revenue_split(
+            creator_split,
+            creator_fee,
+            &mut split_fee_ledgers,
+            &mut fee_ledgers,
+            &bet_mint,
+            program_id,
+        )?,
+        None => {
+            require!(
+                accounts.market_account.creator_split == Pubkey::default(),
+                ProgramErrorCode::InvalidRevenueSplit
+            );
+            fee_ledgers[0].credit(creator_fee)?
+        }
+    }
+
+    match &accounts.service_split {
+        Some(service_split) => credit_revenue_split(
+            service_split,
+            service_fee,
+            &mut split_fee_ledgers,
+            &mut fee_ledgers,
+            &bet_mint,
+            program_id,
+        )?,
+        None => {
+            require!(
+                accounts.market_account.service_split == Pubkey::default(),
+                ProgramErrorCode::InvalidRevenueSplit
+            );
+            // The service ledger, or the creator's when they are the same.
+            let service_fee_ledger = fee_ledgers.len() - 1;
+            fee_ledgers[service_fee_ledger].credit(service_fee)?
+        }
+    }
 
     emit!(MarketSuccess {
-        market_key: ctx.accounts.market_account.market_key,
+        market_key: accounts.market_account.market_key,
         answer_key: correct_answer_key,
         creator_fee: fees.creator_fee,
         service_fee: fees.service_fee,
-        market_remain_tokens: ctx.accounts.market_account.market_remain_tokens,
+        market_remain_tokens: accounts.market_account.market_remain_tokens,
     });
 
     Ok(())
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_pool_vault_to_user;
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, MarketAccount, MarketStatus, MarketType, MARKET_SEED,
+    Role, Roles, ROLES_SEED,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawHouseTokens<'info> {
+    #[account(
+        mut,
+        constraint = roles_account.has_role(&owner.key(), Role::Treasurer, &config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [ROLES_SEED.as_bytes()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub house_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Success || market_account.status == MarketStatus::Adjourn @ ProgramErrorCode::CannotRetrieveToken,
+      constraint = market_account.market_type == MarketType::FixedOdds @ ProgramErrorCode::InvalidMarketType,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct HouseTokensWithdrawn {
+    pub market_key: u64,
+    pub amount: u64,
+}
+
+pub fn withdraw_house_tokens(ctx: Context<WithdrawHouseTokens>) -> Result<()> {
+    let market_account = &mut ctx.accounts.market_account;
+    let amount = market_account.house_tokens;
+    market_account.house_tokens = 0;
+
+    let seeds: &[&[u8]] = &[
+        MARKET_SEED.as_bytes(),
+        &market_account.market_key.to_le_bytes(),
+        &[market_account.bump],
+    ];
+
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.vault_token_account.to_account_info(),
+        &ctx.accounts.house_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.market_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        amount,
+        &[&seeds],
+    )?;
+
+    emit!(HouseTokensWithdrawn {
+        market_key: ctx.accounts.market_account.market_key,
+        amount,
+    });
+
+    Ok(())
+}
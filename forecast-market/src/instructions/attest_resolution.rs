@@ -0,0 +1,120 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ProgramErrorCode, AnswerAccount, MarketAccount, MarketStatus, ResolutionAttestation,
+    ResolutionVotes, ResolverCommittee, ANSWER_SEED, RESOLUTION_VOTES_SEED,
+};
+
+#[derive(Accounts)]
+pub struct AttestResolution<'info> {
+    #[account(
+        mut,
+        constraint = resolver_committee.is_member(&member.key()) @ ProgramErrorCode::NotCommitteeMember
+    )]
+    pub member: Signer<'info>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Finished @ ProgramErrorCode::MarketNotFinished,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+    #[account(
+        address = market_account.resolver_committee @ ProgramErrorCode::InvalidCommittee
+    )]
+    pub resolver_committee: Box<Account<'info, ResolverCommittee>>,
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = ResolutionVotes::MAX_SIZE,
+        seeds = [RESOLUTION_VOTES_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump,
+    )]
+    pub resolution_votes: Box<Account<'info, ResolutionVotes>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ResolutionAttested {
+    pub market_key: u64,
+    pub member: Pubkey,
+    pub answer_key: u64,
+}
+
+#[event]
+pub struct ResolutionAgreed {
+    pub market_key: u64,
+    pub answer_key: u64,
+}
+
+#[event]
+pub struct MarketDisputed {
+    pub market_key: u64,
+}
+
+/// Record a committee member's answer. Once `threshold` members agree,
+/// `success_market` can resolve the market with that answer; a conflicting
+/// attestation moves the market to `Disputed` instead.
+pub fn attest_resolution(ctx: Context<AttestResolution>, answer_key: u64) -> Result<()> {
+    require!(
+        ctx.accounts
+            .answer_account
+            .answers
+            .iter()
+            .any(|answer| answer.answer_key == answer_key),
+        ProgramErrorCode::MarketDoesNotContainAnswerKey
+    );
+
+    let member = ctx.accounts.member.key();
+    let market_account = ctx.accounts.market_account.deref_mut();
+    let resolution_votes = ctx.accounts.resolution_votes.deref_mut();
+
+    resolution_votes.bump = ctx.bumps.resolution_votes;
+    resolution_votes.market_key = market_account.market_key;
+    resolution_votes.committee = market_account.resolver_committee;
+
+    require!(
+        !resolution_votes
+            .attestations
+            .iter()
+            .any(|attestation| attestation.member == member),
+        ProgramErrorCode::AlreadyAttested
+    );
+    resolution_votes
+        .attestations
+        .push(ResolutionAttestation { member, answer_key });
+
+    emit!(ResolutionAttested {
+        market_key: market_account.market_key,
+        member,
+        answer_key,
+    });
+
+    if resolution_votes
+        .attestations
+        .iter()
+        .any(|attestation| attestation.answer_key != answer_key)
+    {
+        market_account.status = MarketStatus::Disputed;
+
+        emit!(MarketDisputed {
+            market_key: market_account.market_key,
+        });
+    } else if resolution_votes.attestations.len()
+        >= ctx.accounts.resolver_committee.threshold as usize
+    {
+        resolution_votes.agreed_answer_key = Some(answer_key);
+
+        emit!(ResolutionAgreed {
+            market_key: market_account.market_key,
+            answer_key,
+        });
+    }
+
+    Ok(())
+}
@@ -5,22 +5,32 @@ use anchor_spl::{associated_token::AssociatedToken, token::Token};
 
 use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
 
-use crate::helper::{get_transfer_inverse_fee, transfer_from_user_to_pool_vault};
+use crate::helper::{
+    calculate_fixed_odds_payout, get_transfer_inverse_fee, transfer_from_pool_vault_to_user,
+    transfer_from_user_to_pool_vault,
+};
 use crate::{
-    error::ProgramErrorCode, AnswerAccount,
-    BettingAccount, ConfigAccount, MarketAccount, MarketStatus, BETTING_SEED,
+    error::ProgramErrorCode, session_owner, AnswerAccount, BettingAccount, ConfigAccount,
+    MarketAccount, MarketStatus, MarketType, SessionAccount, ANSWER_SEED, BETTING_SEED,
+    SESSION_SEED,
 };
 
 #[derive(Accounts)]
 #[instruction(answer_key: u64)]
 pub struct Bet<'info> {
+    /// The betting wallet, or a session key acting for it.
     #[account(mut)]
     pub voter: Signer<'info>,
     #[account(mut)]
+    pub session_account: Option<Box<Account<'info, SessionAccount>>>,
+    #[account(mut)]
     pub config_account: Account<'info, ConfigAccount>,
     #[account(mut)]
     pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
-    #[account(mut)]
+    #[account(
+        mut,
+        token::authority = session_owner(&session_account, &voter.key())
+    )]
     pub user_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(
         init_if_needed,
@@ -31,19 +41,22 @@ pub struct Bet<'info> {
     pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
       mut,
-      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved
+      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved,
+      constraint = market_account.market_type != MarketType::Lmsr @ ProgramErrorCode::InvalidMarketType,
     )]
     pub market_account: Box<Account<'info, MarketAccount>>,
     #[account(
       mut,
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
       constraint = market_account.exist == true @ ProgramErrorCode::AnswerNotExists,
     )]
     pub answer_account: Box<Account<'info, AnswerAccount>>,
     #[account(
       init_if_needed,
       payer = voter,
-      space = MarketAccount::LEN,
-      seeds = [BETTING_SEED.as_bytes(), voter.key().as_ref(), &market_account.market_key.to_le_bytes(), &answer_key.to_le_bytes()],
+      space = BettingAccount::MAX_SIZE,
+      seeds = [BETTING_SEED.as_bytes(), session_owner(&session_account, &voter.key()).as_ref(), &market_account.market_key.to_le_bytes(), &answer_key.to_le_bytes()],
       bump,
     )]
     pub bet_account: Box<Account<'info, BettingAccount>>,
@@ -61,29 +74,92 @@ pub struct Bet<'info> {
 #[event]
 pub struct BetPlaced {
     pub voter: Pubkey,
-    pub market_key: Pubkey,
+    pub market: Pubkey,
+    pub market_key: u64,
     pub answer_key: u64,
+    pub amount: u64,
 }
 
 pub fn bet(ctx: Context<Bet>, answer_key: u64, amount: u64) -> Result<()> {
-    let market_key = ctx.accounts.market_account.market_key.clone();
+    let bettor = session_owner(&ctx.accounts.session_account, &ctx.accounts.voter.key());
     let betting_account = ctx.accounts.bet_account.deref_mut();
     let market_account = ctx.accounts.market_account.deref_mut();
     let answer_account = ctx.accounts.answer_account.deref_mut();
 
     let amount_transfer_fee = get_transfer_inverse_fee(ctx.accounts.bet_mint.clone(), amount ).unwrap();
-    
+    let transfer_amount = amount.checked_sub(amount_transfer_fee).unwrap();
+
     //send token to the pool
-    transfer_from_user_to_pool_vault(
-        &ctx.accounts.user_token_account.to_account_info(),
-        &ctx.accounts.vault_token_account.to_account_info(),
-        ctx.accounts.bet_mint.clone(),
-        &ctx.accounts.voter.to_account_info(),
-        &ctx.accounts.token_program.to_account_info(),
-        Some(&ctx.accounts.token_2022_program.to_account_info()),
-        amount.checked_sub(amount_transfer_fee).unwrap(),
+    if let Some(session_account) = ctx.accounts.session_account.as_mut() {
+        let clock = Clock::get()?;
+        session_account.authorize_spend(
+            &ctx.accounts.voter.key(),
+            market_account.market_key,
+            amount,
+            clock.unix_timestamp as u64,
+        )?;
+
+        // The session PDA moves the tokens as delegate of the owner's account.
+        let session_seeds: &[&[u8]] = &[
+            SESSION_SEED.as_bytes(),
+            session_account.owner.as_ref(),
+            session_account.session_key.as_ref(),
+            &[session_account.bump],
+        ];
+
+        transfer_from_pool_vault_to_user(
+            &ctx.accounts.user_token_account.to_account_info(),
+            &ctx.accounts.vault_token_account.to_account_info(),
+            ctx.accounts.bet_mint.clone(),
+            &session_account.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&ctx.accounts.token_2022_program.to_account_info()),
+            transfer_amount,
+            &[&session_seeds],
+        )?;
+    } else {
+        transfer_from_user_to_pool_vault(
+            &ctx.accounts.user_token_account.to_account_info(),
+            &ctx.accounts.vault_token_account.to_account_info(),
+            ctx.accounts.bet_mint.clone(),
+            &ctx.accounts.voter.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&ctx.accounts.token_2022_program.to_account_info()),
+            transfer_amount,
+        )?;
+    }
+
+    record_bet(
+        market_account,
+        answer_account,
+        betting_account,
+        ctx.bumps.bet_account,
+        bettor,
+        answer_key,
+        amount,
     )?;
 
+    emit!(BetPlaced {
+        voter: bettor,
+        market: ctx.accounts.market_account.key(),
+        market_key: ctx.accounts.market_account.market_key,
+        answer_key,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Book `amount` on `answer_key` for `voter` once the tokens are in the vault.
+pub(crate) fn record_bet(
+    market_account: &mut MarketAccount,
+    answer_account: &mut AnswerAccount,
+    betting_account: &mut BettingAccount,
+    bump: u8,
+    voter: Pubkey,
+    answer_key: u64,
+    amount: u64,
+) -> Result<()> {
     if !answer_account
         .answers
         .iter()
@@ -102,21 +178,52 @@ pub fn bet(ctx: Context<Bet>, answer_key: u64, amount: u64) -> Result<()> {
 
     let clock = Clock::get()?;
 
-    betting_account.bump = ctx.bumps.bet_account;
-    betting_account.market_key = market_key;
+    if !betting_account.exist {
+        market_account.reward_bet_count += 1;
+    }
+
+    betting_account.version = BettingAccount::VERSION;
+    betting_account.bump = bump;
+    betting_account.market_key = market_account.market_key;
     betting_account.answer_key = answer_key;
-    betting_account.voter = ctx.accounts.voter.key();
+    betting_account.voter = voter;
     betting_account.tokens += amount;
     betting_account.create_time = clock.unix_timestamp as u64;
     betting_account.exist = true;
 
     market_account.market_total_tokens += amount;
-
-    emit!(BetPlaced {
-        voter: ctx.accounts.voter.key(),
-        market_key: ctx.accounts.market_account.key(),
-        answer_key,
-    });
+    market_account.reward_stake_time = market_account
+        .reward_stake_time
+        .checked_add((amount as u128) * (betting_account.create_time as u128))
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    if market_account.market_type == MarketType::FixedOdds {
+        let answer_odds = answer_account
+            .odds
+            .iter_mut()
+            .find(|answer_odds| answer_odds.answer_key == answer_key)
+            .ok_or(ProgramErrorCode::OddsNotSet)?;
+
+        let payout = calculate_fixed_odds_payout(amount, answer_odds.odds)?;
+        answer_odds.liability = answer_odds
+            .liability
+            .checked_add(payout)
+            .ok_or(ProgramErrorCode::Overflow)?;
+
+        let available_tokens = market_account
+            .house_tokens
+            .checked_add(market_account.market_total_tokens)
+            .ok_or(ProgramErrorCode::Overflow)?;
+        require!(
+            answer_account.max_liability() <= available_tokens,
+            ProgramErrorCode::LiabilityExceedsHouse
+        );
+
+        betting_account.locked_payout = betting_account
+            .locked_payout
+            .checked_add(payout)
+            .ok_or(ProgramErrorCode::Overflow)?;
+    }
 
     Ok(())
 }
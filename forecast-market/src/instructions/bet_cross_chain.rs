@@ -8,7 +8,7 @@ use wormhole_anchor_sdk::wormhole::{self, program::Wormhole};
 
 // use crate::helper::{get_transfer_inverse_fee, transfer_from_user_to_pool_vault};
 use crate::{
-    error::ProgramErrorCode, message::{PredixMessage, PredixVaa, MESSAGE_MAX_LENGTH}, AnswerAccount, BettingCrossChainAccount, BettingCrossChainData, ConfigAccount, ForeignEmitter, MarketAccount, MarketStatus, Received, BETTING_CROSS_CHAIN_SEED
+    error::ProgramErrorCode, message::{PredixMessage, PredixQuestVaa, MESSAGE_MAX_LENGTH}, AnswerAccount, BettingCrossChainAccount, BettingCrossChainData, ConfigAccount, ForeignEmitter, MarketAccount, MarketStatus, MarketType, Received, BETTING_CROSS_CHAIN_SEED
 };
 #[derive(Accounts)]
 #[instruction(answer_key: u64, vaa_hash: [u8; 32])]
@@ -21,7 +21,8 @@ pub struct BetCrossChain<'info> {
     pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
       mut,
-      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved
+      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved,
+      constraint = market_account.market_type == MarketType::Parimutuel @ ProgramErrorCode::InvalidMarketType,
     )]
     pub market_account: Box<Account<'info, MarketAccount>>,
     #[account(
@@ -41,7 +42,7 @@ pub struct BetCrossChain<'info> {
     )]
     /// Verified Wormhole message account. The Wormhole program verified
     /// signatures and posted the account data here. Read-only.
-    pub posted: Account<'info, PredixVaa>,
+    pub posted: Account<'info, PredixQuestVaa>,
     #[account(
       init_if_needed,
       payer = predix_owner,
@@ -84,6 +85,7 @@ pub struct BetCrossChainPlaced {
     pub chain_id: u16,
     pub market_key: u64,
     pub answer_key: u64,
+    pub amount: u64,
 }
 
 pub fn bet_cross_chain(ctx: Context<BetCrossChain>, answer_key: u64, vaa_hash: [u8; 32]) -> Result<()> {
@@ -121,6 +123,7 @@ pub fn bet_cross_chain(ctx: Context<BetCrossChain>, answer_key: u64, vaa_hash: [
             }
         }
 
+        betting_cross_chain_account.version = BettingCrossChainAccount::VERSION;
         betting_cross_chain_account.bump = ctx.bumps.bet_cross_chain_account;
         betting_cross_chain_account.market_key = data.market_key;
         betting_cross_chain_account.answer_key = data.answer_key;
@@ -137,6 +140,7 @@ pub fn bet_cross_chain(ctx: Context<BetCrossChain>, answer_key: u64, vaa_hash: [
             chain_id: data.chain_id,
             market_key: data.market_key,
             answer_key: data.answer_key,
+            amount: data.tokens,
         });
         Ok(())
     } else {
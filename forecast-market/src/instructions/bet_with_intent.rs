@@ -0,0 +1,183 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::{
+    get_transfer_inverse_fee, transfer_from_pool_vault_to_user, verify_ed25519_signature,
+};
+use crate::instructions::bet::{record_bet, BetPlaced};
+use crate::{
+    error::ProgramErrorCode, AnswerAccount, BetIntent, BettingAccount, ConfigAccount,
+    IntentNonceAccount, MarketAccount, MarketStatus, MarketType, ANSWER_SEED, BETTING_SEED,
+    INTENT_ESCROW_SEED, INTENT_NONCE_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(answer_key: u64)]
+pub struct BetWithIntent<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+    /// CHECK: must match the key that signed the intent, checked against the
+    /// ed25519 instruction.
+    pub voter: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// The voter's intent escrow, funded with `fund_intent_escrow`.
+    #[account(
+        mut,
+        seeds = [INTENT_ESCROW_SEED.as_bytes(), voter.key().as_ref(), bet_mint.key().as_ref()],
+        bump,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        associated_token::mint = bet_mint,
+        associated_token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved,
+      constraint = market_account.market_type != MarketType::Lmsr @ ProgramErrorCode::InvalidMarketType,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      mut,
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+    #[account(
+      init_if_needed,
+      payer = relayer,
+      space = BettingAccount::MAX_SIZE,
+      seeds = [BETTING_SEED.as_bytes(), voter.key().as_ref(), &market_account.market_key.to_le_bytes(), &answer_key.to_le_bytes()],
+      bump,
+    )]
+    pub bet_account: Box<Account<'info, BettingAccount>>,
+    #[account(
+      mut,
+      seeds = [INTENT_NONCE_SEED.as_bytes(), voter.key().as_ref()],
+      bump = nonce_account.bump,
+    )]
+    pub nonce_account: Box<Account<'info, IntentNonceAccount>>,
+    /// CHECK: address is checked against the instructions sysvar id.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct BetIntentExecuted {
+    pub voter: Pubkey,
+    pub relayer: Pubkey,
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub amount: u64,
+    pub nonce: u64,
+}
+
+/// Place a bet the voter signed off-chain, paid from their intent escrow. The
+/// transaction must carry an ed25519 program instruction over
+/// `BetIntent::message` right before this one.
+pub fn bet_with_intent(
+    ctx: Context<BetWithIntent>,
+    answer_key: u64,
+    amount: u64,
+    nonce: u64,
+    expiry: u64,
+) -> Result<()> {
+    let voter = ctx.accounts.voter.key();
+    let market_key = ctx.accounts.market_account.market_key;
+
+    let clock = Clock::get()?;
+    require!(
+        (clock.unix_timestamp as u64) <= expiry,
+        ProgramErrorCode::IntentExpired
+    );
+
+    let intent = BetIntent {
+        market_key,
+        answer_key,
+        amount,
+        nonce,
+        expiry,
+    };
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &voter,
+        &intent.message()?,
+    )?;
+
+    let nonce_account = ctx.accounts.nonce_account.deref_mut();
+    require!(nonce == nonce_account.next_nonce, ProgramErrorCode::InvalidNonce);
+    nonce_account.next_nonce = nonce
+        .checked_add(1)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let amount_transfer_fee = get_transfer_inverse_fee(ctx.accounts.bet_mint.clone(), amount)?;
+
+    let seeds: &[&[u8]] = &[
+        INTENT_NONCE_SEED.as_bytes(),
+        voter.as_ref(),
+        &[ctx.accounts.nonce_account.bump],
+    ];
+
+    // The nonce PDA pays the bet out of the voter's intent escrow.
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        &ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.nonce_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        amount
+            .checked_sub(amount_transfer_fee)
+            .ok_or(ProgramErrorCode::MathOperationError)?,
+        &[&seeds],
+    )?;
+
+    record_bet(
+        ctx.accounts.market_account.deref_mut(),
+        ctx.accounts.answer_account.deref_mut(),
+        ctx.accounts.bet_account.deref_mut(),
+        ctx.bumps.bet_account,
+        voter,
+        answer_key,
+        amount,
+    )?;
+
+    emit!(BetPlaced {
+        voter,
+        market: ctx.accounts.market_account.key(),
+        market_key,
+        answer_key,
+        amount,
+    });
+
+    emit!(BetIntentExecuted {
+        voter,
+        relayer: ctx.accounts.relayer.key(),
+        market_key,
+        answer_key,
+        amount,
+        nonce,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::{lmsr_buy_cost, transfer_from_user_to_pool_vault};
+use crate::{
+    error::ProgramErrorCode, LmsrAccount, MarketAccount, MarketStatus, MarketType,
+    SharePositionAccount, LMSR_SEED, SHARE_POSITION_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(answer_key: u64)]
+pub struct BuyShares<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = bet_mint,
+        associated_token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved,
+      constraint = market_account.market_type == MarketType::Lmsr @ ProgramErrorCode::InvalidMarketType,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      mut,
+      seeds = [LMSR_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump = lmsr_account.bump,
+    )]
+    pub lmsr_account: Box<Account<'info, LmsrAccount>>,
+    #[account(
+      init_if_needed,
+      payer = voter,
+      space = SharePositionAccount::MAX_SIZE,
+      seeds = [SHARE_POSITION_SEED.as_bytes(), voter.key().as_ref(), &market_account.market_key.to_le_bytes(), &answer_key.to_le_bytes()],
+      bump,
+    )]
+    pub position_account: Box<Account<'info, SharePositionAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SharesBought {
+    pub voter: Pubkey,
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub shares: u64,
+    pub cost: u64,
+}
+
+pub fn buy_shares(ctx: Context<BuyShares>, answer_key: u64, shares: u64, max_cost: u64) -> Result<()> {
+    let lmsr_account = ctx.accounts.lmsr_account.deref_mut();
+
+    let index = lmsr_account
+        .outcome_index(answer_key)
+        .ok_or(ProgramErrorCode::AnswerNotExists)?;
+
+    let cost = lmsr_buy_cost(
+        &lmsr_account.total_shares(),
+        index,
+        shares,
+        lmsr_account.liquidity_param,
+    )?;
+    require!(cost <= max_cost, ProgramErrorCode::SlippageExceeded);
+
+    transfer_from_user_to_pool_vault(
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.voter.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        cost,
+    )?;
+
+    let outcome = &mut lmsr_account.outcomes[index];
+    outcome.total_shares = outcome
+        .total_shares
+        .checked_add(shares)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let market_account = ctx.accounts.market_account.deref_mut();
+    market_account.market_total_tokens = market_account
+        .market_total_tokens
+        .checked_add(cost)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let position_account = ctx.accounts.position_account.deref_mut();
+    position_account.bump = ctx.bumps.position_account;
+    position_account.market_key = market_account.market_key;
+    position_account.answer_key = answer_key;
+    position_account.voter = ctx.accounts.voter.key();
+    position_account.shares = position_account
+        .shares
+        .checked_add(shares)
+        .ok_or(ProgramErrorCode::Overflow)?;
+    position_account.exist = true;
+
+    emit!(SharesBought {
+        voter: ctx.accounts.voter.key(),
+        market_key: market_account.market_key,
+        answer_key,
+        shares,
+        cost,
+    });
+
+    Ok(())
+}
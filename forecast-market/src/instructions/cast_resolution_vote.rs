@@ -0,0 +1,138 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_user_to_pool_vault;
+use crate::{
+    error::ProgramErrorCode, AnswerAccount, ConfigAccount, DisputeTally, MarketAccount,
+    MarketStatus, ResolutionVote, ANSWER_SEED, CONFIG_SEED, DISPUTE_TALLY_SEED,
+    RESOLUTION_VOTE_SEED, VOTE_LOCK_SEED,
+};
+
+#[derive(Accounts)]
+pub struct CastResolutionVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, ConfigAccount>>,
+    #[account(
+      constraint = market_account.status == MarketStatus::Disputed @ ProgramErrorCode::MarketNotDisputed,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+    #[account(
+        address = config_account.reward_mint @ ProgramErrorCode::InvalidRewardMint
+    )]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = voter
+    )]
+    pub voter_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [DISPUTE_TALLY_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump = dispute_tally.bump,
+    )]
+    pub dispute_tally: Box<Account<'info, DisputeTally>>,
+    #[account(
+        mut,
+        seeds = [VOTE_LOCK_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump,
+    )]
+    pub vote_lock_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ResolutionVote::LEN,
+        seeds = [RESOLUTION_VOTE_SEED.as_bytes(), &market_account.market_key.to_le_bytes(), voter.key().as_ref()],
+        bump,
+    )]
+    pub resolution_vote: Box<Account<'info, ResolutionVote>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ResolutionVoteCast {
+    pub market_key: u64,
+    pub voter: Pubkey,
+    pub answer_key: u64,
+    pub amount: u64,
+}
+
+/// Lock reward tokens on an answer of a disputed market until the vote is
+/// settled. Adding to a vote keeps it on the same answer.
+pub fn cast_resolution_vote(
+    ctx: Context<CastResolutionVote>,
+    answer_key: u64,
+    amount: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        (clock.unix_timestamp as u64) < ctx.accounts.dispute_tally.vote_end,
+        ProgramErrorCode::VotingClosed
+    );
+    require!(amount > 0, ProgramErrorCode::InvalidVoteAmount);
+    require!(
+        ctx.accounts
+            .answer_account
+            .answers
+            .iter()
+            .any(|answer| answer.answer_key == answer_key),
+        ProgramErrorCode::MarketDoesNotContainAnswerKey
+    );
+
+    transfer_from_user_to_pool_vault(
+        &ctx.accounts.voter_reward_token_account.to_account_info(),
+        &ctx.accounts.vote_lock_token_account.to_account_info(),
+        ctx.accounts.reward_mint.clone(),
+        &ctx.accounts.voter.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        amount,
+    )?;
+
+    let market_key = ctx.accounts.market_account.market_key;
+    let resolution_vote = ctx.accounts.resolution_vote.deref_mut();
+
+    require!(
+        resolution_vote.amount == 0 || resolution_vote.answer_key == answer_key,
+        ProgramErrorCode::VoteAnswerMismatch
+    );
+
+    resolution_vote.bump = ctx.bumps.resolution_vote;
+    resolution_vote.market_key = market_key;
+    resolution_vote.voter = ctx.accounts.voter.key();
+    resolution_vote.answer_key = answer_key;
+    resolution_vote.amount = resolution_vote
+        .amount
+        .checked_add(amount)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    ctx.accounts.dispute_tally.add_weight(answer_key, amount)?;
+
+    emit!(ResolutionVoteCast {
+        market_key,
+        voter: ctx.accounts.voter.key(),
+        answer_key,
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use wormhole_anchor_sdk::wormhole::{self, program::Wormhole};
+
+use crate::helper::calculate_claim_amounts;
+use crate::instructions::claim_token::book_claim;
+use crate::{
+    error::ProgramErrorCode, message::PredixMessage, AnswerAccount, BettingAccount,
+    BettingCrossChainAccount, MarketAccount, MarketStatus, ANSWER_SEED, CROSS_CHAIN_PAYOUT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct ClaimCrossChain<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Success || market_account.status == MarketStatus::Adjourn @ ProgramErrorCode::CannotClaimToken,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+    #[account(
+      mut,
+      constraint = bet_cross_chain_account.exist == true @ ProgramErrorCode::CannotClaimToken,
+      constraint = bet_cross_chain_account.market_key == market_account.market_key @ ProgramErrorCode::CannotClaimToken,
+      constraint = bet_cross_chain_account.settled == false @ ProgramErrorCode::AlreadySettled,
+    )]
+    pub bet_cross_chain_account: Box<Account<'info, BettingCrossChainAccount>>,
+
+    pub wormhole_program: Program<'info, Wormhole>,
+    #[account(
+        mut,
+        seeds = [wormhole::BridgeData::SEED_PREFIX],
+        bump,
+        seeds::program = wormhole_program.key
+    )]
+    /// Wormhole bridge data, read for the message fee.
+    pub wormhole_bridge: Box<Account<'info, wormhole::BridgeData>>,
+    #[account(
+        mut,
+        seeds = [wormhole::FeeCollector::SEED_PREFIX],
+        bump,
+        seeds::program = wormhole_program.key
+    )]
+    /// CHECK: Wormhole fee collector, verified by seeds.
+    pub wormhole_fee_collector: UncheckedAccount<'info>,
+    #[account(
+        seeds = [wormhole::SEED_PREFIX_EMITTER],
+        bump,
+    )]
+    /// CHECK: program emitter PDA, only used as the Wormhole message signer.
+    pub wormhole_emitter: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [wormhole::SequenceTracker::SEED_PREFIX, wormhole_emitter.key().as_ref()],
+        bump,
+        seeds::program = wormhole_program.key
+    )]
+    /// CHECK: emitter sequence, created by Wormhole on the first message.
+    pub wormhole_sequence: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [CROSS_CHAIN_PAYOUT_SEED.as_bytes(), bet_cross_chain_account.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: Wormhole message account, one per settled cross-chain bet.
+    pub wormhole_message: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CrossChainPayoutPosted {
+    pub chain_id: u16,
+    pub voter_wallet_address: [u8; 32],
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub amount: u64,
+}
+
+/// Settle a cross-chain bet by posting a `PredixMessage::Payout` through
+/// Wormhole, so the contract on the voter's chain can release the funds.
+pub fn claim_cross_chain(ctx: Context<ClaimCrossChain>) -> Result<()> {
+    let bet_cross_chain_account = &mut ctx.accounts.bet_cross_chain_account;
+
+    // Claimed like a local bet, so the service fee is charged and accrued the
+    // same way. Rewards are only paid on this chain, so none is booked.
+    let mut betting_account = BettingAccount {
+        version: BettingAccount::VERSION,
+        market_key: bet_cross_chain_account.market_key,
+        answer_key: bet_cross_chain_account.answer_key,
+        tokens: bet_cross_chain_account.tokens,
+        create_time: bet_cross_chain_account.create_time,
+        exist: true,
+        ..Default::default()
+    };
+    let mut claim_amounts = calculate_claim_amounts(
+        &ctx.accounts.market_account,
+        &ctx.accounts.answer_account,
+        &betting_account,
+        0,
+    )?;
+    claim_amounts.reward_amount = 0;
+    book_claim(
+        &mut ctx.accounts.market_account,
+        &mut betting_account,
+        &claim_amounts,
+    )?;
+    let amount = claim_amounts.receive_tokens;
+
+    bet_cross_chain_account.settled = true;
+
+    let payload = PredixMessage::Payout {
+        market_key: bet_cross_chain_account.market_key,
+        answer_key: bet_cross_chain_account.answer_key,
+        chain_id: bet_cross_chain_account.chain_id,
+        voter_wallet_address: bet_cross_chain_account.voter_wallet_address,
+        token_address: bet_cross_chain_account.token_address,
+        amount,
+    }
+    .try_to_vec()?;
+
+    let fee = ctx.accounts.wormhole_bridge.fee();
+    if fee > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    let bet_cross_chain_key = bet_cross_chain_account.key();
+    wormhole::post_message(
+        CpiContext::new_with_signer(
+            ctx.accounts.wormhole_program.to_account_info(),
+            wormhole::PostMessage {
+                config: ctx.accounts.wormhole_bridge.to_account_info(),
+                message: ctx.accounts.wormhole_message.to_account_info(),
+                emitter: ctx.accounts.wormhole_emitter.to_account_info(),
+                sequence: ctx.accounts.wormhole_sequence.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                clock: ctx.accounts.clock.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[
+                &[
+                    CROSS_CHAIN_PAYOUT_SEED.as_bytes(),
+                    bet_cross_chain_key.as_ref(),
+                    &[ctx.bumps.wormhole_message],
+                ],
+                &[wormhole::SEED_PREFIX_EMITTER, &[ctx.bumps.wormhole_emitter]],
+            ],
+        ),
+        0,
+        payload,
+        wormhole::Finality::Finalized,
+    )?;
+
+    let bet_cross_chain_account = &ctx.accounts.bet_cross_chain_account;
+    emit!(CrossChainPayoutPosted {
+        chain_id: bet_cross_chain_account.chain_id,
+        voter_wallet_address: bet_cross_chain_account.voter_wallet_address,
+        market_key: bet_cross_chain_account.market_key,
+        answer_key: bet_cross_chain_account.answer_key,
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use crate::helper::{transfer_from_pool_vault_to_user, transfer_market_reward};
+use crate::instructions::claim_token::{
+    settle_claim, staking_discount_bps, RewardClaimed, TokenClaimed,
+};
+use crate::{
+    error::ProgramErrorCode, AnswerAccount, BettingAccount, ConfigAccount, MarketAccount,
+    MarketStatus, RewardStakeAccount, ANSWER_SEED, CONFIG_SEED, FEE_DISCOUNT_SEED, MARKET_SEED,
+    REWARD_STAKE_SEED,
+};
+
+pub const CLAIM_MANY_ACCOUNTS_PER_CLAIM: usize = 5;
+
+/// Remaining accounts: one `(market_account, answer_account, bet_account,
+/// vault_bet_token_account, vault_reward_token_account)` tuple per claim.
+/// Every market has to use `bet_mint`.
+#[derive(Accounts)]
+pub struct ClaimMany<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = voter
+    )]
+    pub user_bet_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = voter,
+        associated_token::mint = reward_mint,
+        associated_token::authority = voter
+    )]
+    pub user_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: service fee discount tiers, empty until `set_fee_discount_tiers`.
+    #[account(
+        seeds = [FEE_DISCOUNT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_discount_tiers: UncheckedAccount<'info>,
+    /// The voter's locked reward tokens, which pick the discount tier.
+    #[account(
+        seeds = [REWARD_STAKE_SEED.as_bytes(), voter.key().as_ref()],
+        bump = reward_stake_account.bump,
+    )]
+    pub reward_stake_account: Option<Box<Account<'info, RewardStakeAccount>>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+struct VaultPayout<'info> {
+    vault: AccountInfo<'info>,
+    market: AccountInfo<'info>,
+    market_key: u64,
+    bump: u8,
+    amount: u64,
+}
+
+pub fn claim_many<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimMany<'info>>) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    require!(
+        !remaining_accounts.is_empty()
+            && remaining_accounts.len() % CLAIM_MANY_ACCOUNTS_PER_CLAIM == 0,
+        ProgramErrorCode::CannotClaimToken
+    );
+
+    let voter = ctx.accounts.voter.key();
+
+    let discount_bps = staking_discount_bps(
+        &ctx.accounts.fee_discount_tiers,
+        ctx.accounts.reward_stake_account.as_deref(),
+    )?;
+
+    let mut vault_payouts: Vec<VaultPayout> = Vec::new();
+    let mut total_reward_amount: u64 = 0;
+
+    for accounts in remaining_accounts.chunks(CLAIM_MANY_ACCOUNTS_PER_CLAIM) {
+        let mut market_account = Account::<MarketAccount>::try_from(&accounts[0])?;
+        let answer_account = Account::<AnswerAccount>::try_from(&accounts[1])?;
+        let mut bet_account = Account::<BettingAccount>::try_from(&accounts[2])?;
+        let vault_bet_token_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[3])?;
+        let vault_reward_token_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[4])?;
+
+        require!(
+            market_account.status == MarketStatus::Success
+                || market_account.status == MarketStatus::Adjourn,
+            ProgramErrorCode::CannotClaimToken
+        );
+        require!(
+            market_account.bet_mint == ctx.accounts.bet_mint.key()
+                && vault_bet_token_account.mint == market_account.bet_mint,
+            ProgramErrorCode::InvalidBetMint
+        );
+        require!(
+            vault_bet_token_account.owner == market_account.key(),
+            ProgramErrorCode::CannotClaimToken
+        );
+        require!(
+            bet_account.voter == voter && bet_account.market_key == market_account.market_key,
+            ProgramErrorCode::Unauthorized
+        );
+
+        let (answer_address, _) = Pubkey::find_program_address(
+            &[ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+            ctx.program_id,
+        );
+        require!(
+            answer_account.key() == answer_address,
+            ProgramErrorCode::InvalidAnswerKey
+        );
+
+        require!(
+            market_account.vesting_period == 0,
+            ProgramErrorCode::VestingNotSupported
+        );
+
+        let claim_amounts = settle_claim(
+            &mut market_account,
+            &answer_account,
+            &mut bet_account,
+            discount_bps,
+        )?;
+
+        total_reward_amount = total_reward_amount
+            .checked_add(claim_amounts.reward_amount)
+            .ok_or(ProgramErrorCode::Overflow)?;
+
+        if claim_amounts.receive_tokens > 0 {
+            match vault_payouts
+                .iter_mut()
+                .find(|payout| payout.vault.key == accounts[3].key)
+            {
+                Some(payout) => {
+                    payout.amount = payout
+                        .amount
+                        .checked_add(claim_amounts.receive_tokens)
+                        .ok_or(ProgramErrorCode::Overflow)?;
+                }
+                None => vault_payouts.push(VaultPayout {
+                    vault: accounts[3].to_account_info(),
+                    market: accounts[0].to_account_info(),
+                    market_key: market_account.market_key,
+                    bump: market_account.bump,
+                    amount: claim_amounts.receive_tokens,
+                }),
+            }
+        }
+
+        emit!(TokenClaimed {
+            receiver: voter,
+            market_key: market_account.market_key,
+            betting_key: bet_account.answer_key,
+            received_tokens: claim_amounts.receive_tokens,
+        });
+
+        transfer_market_reward(
+            &vault_reward_token_account,
+            &ctx.accounts.user_reward_token_account.to_account_info(),
+            ctx.accounts.reward_mint.clone(),
+            &market_account,
+            &ctx.accounts.config_account,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.token_2022_program.to_account_info(),
+            claim_amounts.reward_amount,
+        )?;
+
+        // Persist before the next tuple so a market listed twice sees the update.
+        market_account.exit(ctx.program_id)?;
+        bet_account.close(ctx.accounts.voter.to_account_info())?;
+    }
+
+    for payout in vault_payouts {
+        let bet_seeds: &[&[u8]] = &[
+            MARKET_SEED.as_bytes(),
+            &payout.market_key.to_le_bytes(),
+            &[payout.bump],
+        ];
+
+        transfer_from_pool_vault_to_user(
+            &payout.vault,
+            &ctx.accounts.user_bet_token_account.to_account_info(),
+            ctx.accounts.bet_mint.clone(),
+            &payout.market,
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&ctx.accounts.token_2022_program.to_account_info()),
+            payout.amount,
+            &[&bet_seeds],
+        )?;
+    }
+
+    if total_reward_amount > 0 {
+        emit!(RewardClaimed {
+            receiver: voter,
+            amount: total_reward_amount
+        })
+    }
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::{lmsr_price, transfer_from_pool_vault_to_user};
+use crate::{
+    error::ProgramErrorCode, LmsrAccount, MarketAccount, MarketStatus, MarketType,
+    SharePositionAccount, LMSR_SEED, MARKET_SEED,
+};
+
+#[derive(Accounts)]
+pub struct ClaimShares<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Success || market_account.status == MarketStatus::Adjourn @ ProgramErrorCode::CannotClaimToken,
+      constraint = market_account.market_type == MarketType::Lmsr @ ProgramErrorCode::InvalidMarketType,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      seeds = [LMSR_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump = lmsr_account.bump,
+    )]
+    pub lmsr_account: Box<Account<'info, LmsrAccount>>,
+    #[account(
+      mut,
+      close = voter,
+      constraint = position_account.voter == voter.key() @ ProgramErrorCode::Unauthorized,
+      constraint = position_account.market_key == market_account.market_key @ ProgramErrorCode::CannotClaimToken,
+    )]
+    pub position_account: Box<Account<'info, SharePositionAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SharesClaimed {
+    pub receiver: Pubkey,
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub shares: u64,
+    pub received_tokens: u64,
+}
+
+/// Winning shares redeem 1:1. When the market is adjourned every position is
+/// bought back at the price the market closed at.
+pub fn claim_shares(ctx: Context<ClaimShares>) -> Result<()> {
+    let market_account = ctx.accounts.market_account.deref_mut();
+    let lmsr_account = &ctx.accounts.lmsr_account;
+    let position_account = &ctx.accounts.position_account;
+
+    let receive_tokens = if market_account.status == MarketStatus::Success {
+        if position_account.answer_key == market_account.correct_answer_key {
+            position_account.shares
+        } else {
+            0
+        }
+    } else {
+        let index = lmsr_account
+            .outcome_index(position_account.answer_key)
+            .ok_or(ProgramErrorCode::InvalidAnswerKey)?;
+        let price = lmsr_price(
+            &lmsr_account.total_shares(),
+            index,
+            lmsr_account.liquidity_param,
+        )?;
+        ((position_account.shares as u128 * price) >> 64) as u64
+    };
+
+    market_account.market_remain_tokens = market_account
+        .market_remain_tokens
+        .checked_sub(receive_tokens)
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    if receive_tokens > 0 {
+        let seeds: &[&[u8]] = &[
+            MARKET_SEED.as_bytes(),
+            &market_account.market_key.to_le_bytes(),
+            &[market_account.bump],
+        ];
+
+        transfer_from_pool_vault_to_user(
+            &ctx.accounts.vault_token_account.to_account_info(),
+            &ctx.accounts.user_token_account.to_account_info(),
+            ctx.accounts.bet_mint.clone(),
+            &ctx.accounts.market_account.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&ctx.accounts.token_2022_program.to_account_info()),
+            receive_tokens,
+            &[&seeds],
+        )?;
+    }
+
+    emit!(SharesClaimed {
+        receiver: ctx.accounts.voter.key(),
+        market_key: ctx.accounts.market_account.market_key,
+        answer_key: ctx.accounts.position_account.answer_key,
+        shares: ctx.accounts.position_account.shares,
+        received_tokens: receive_tokens,
+    });
+
+    Ok(())
+}
@@ -4,24 +4,37 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
 use anchor_spl::{associated_token::AssociatedToken, token::Token};
 
-use crate::helper::{get_transfer_inverse_fee, transfer_from_pool_vault_to_user};
+use crate::helper::{
+    calculate_claim_amounts, transfer_from_pool_vault_to_user, transfer_market_reward, ClaimAmounts,
+};
 use crate::{
-    constant::MAX_PERCENTAGE_BASIS_POINTS, error::ProgramErrorCode,
-    helper::calculate_reward_amount, AnswerAccount, BettingAccount, ConfigAccount, MarketAccount,
-    MarketStatus, CONFIG_SEED, MARKET_SEED,
+    error::ProgramErrorCode, session_owner, AnswerAccount, BettingAccount, ConfigAccount,
+    FeeDiscountTiers, MarketAccount, MarketStatus, RewardStakeAccount, SessionAccount,
+    VestingAccount, ANSWER_SEED, FEE_DISCOUNT_SEED, MARKET_SEED, REWARD_STAKE_SEED, VESTING_SEED,
 };
 
 #[derive(Accounts)]
 pub struct ClaimToken<'info> {
+    /// The betting wallet, or a session key acting for it.
     #[account(mut)]
     pub voter: Signer<'info>,
+    pub session_account: Option<Box<Account<'info, SessionAccount>>>,
+    /// CHECK: wallet the bet belongs to, the voter itself or the session owner.
+    #[account(
+        mut,
+        constraint = receiver.key() == session_owner(&session_account, &voter.key()) @ ProgramErrorCode::Unauthorized
+    )]
+    pub receiver: UncheckedAccount<'info>,
     #[account(mut)]
     pub config_account: Account<'info, ConfigAccount>,
     #[account(mut)]
     pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(mut)]
     pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
-    #[account(mut)]
+    #[account(
+        mut,
+        token::authority = receiver
+    )]
     pub user_bet_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
@@ -33,13 +46,13 @@ pub struct ClaimToken<'info> {
         init_if_needed,
         payer = voter,
         associated_token::mint = reward_mint,
-        associated_token::authority = voter
+        associated_token::authority = receiver
     )]
     pub user_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// The market's reward escrow, see `transfer_market_reward`.
     #[account(
         mut,
         token::mint = reward_mint,
-        token::authority = config_account
     )]
     pub vault_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
@@ -50,11 +63,39 @@ pub struct ClaimToken<'info> {
     pub market_account: Box<Account<'info, MarketAccount>>,
     #[account(
         mut,
-        close = voter
+        close = receiver,
+        constraint = bet_account.voter == receiver.key() @ ProgramErrorCode::Unauthorized,
+        constraint = bet_account.market_key == market_account.market_key @ ProgramErrorCode::CannotClaimToken,
     )]
     pub bet_account: Box<Account<'info, BettingAccount>>,
-    #[account(mut)]
+    #[account(
+      mut,
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
     pub answer_account: Box<Account<'info, AnswerAccount>>,
+    /// Required when the market vests this claim, and rejected when it does
+    /// not, see `set_market_vesting`.
+    #[account(
+        init,
+        payer = voter,
+        space = VestingAccount::MAX_SIZE,
+        seeds = [VESTING_SEED.as_bytes(), bet_account.key().as_ref()],
+        bump,
+    )]
+    pub vesting_account: Option<Box<Account<'info, VestingAccount>>>,
+    /// CHECK: service fee discount tiers, empty until `set_fee_discount_tiers`.
+    #[account(
+        seeds = [FEE_DISCOUNT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_discount_tiers: UncheckedAccount<'info>,
+    /// The receiver's locked reward tokens, which pick the discount tier.
+    #[account(
+        seeds = [REWARD_STAKE_SEED.as_bytes(), receiver.key().as_ref()],
+        bump = reward_stake_account.bump,
+    )]
+    pub reward_stake_account: Option<Box<Account<'info, RewardStakeAccount>>>,
 
     pub token_program: Program<'info, Token>,
 
@@ -79,56 +120,93 @@ pub struct RewardClaimed {
     pub amount: u64,
 }
 
+#[event]
+pub struct ClaimVested {
+    pub receiver: Pubkey,
+    pub market_key: u64,
+    pub bet_amount: u64,
+    pub reward_amount: u64,
+    pub end_time: u64,
+}
+
 pub fn claim_token(ctx: Context<ClaimToken>) -> Result<()> {
+    let receiver = ctx.accounts.receiver.key();
     let market_account = ctx.accounts.market_account.deref_mut();
 
-    let config_account = &ctx.accounts.config_account;
+    if let Some(session_account) = &ctx.accounts.session_account {
+        let clock = Clock::get()?;
+        session_account.authorize(
+            &ctx.accounts.voter.key(),
+            market_account.market_key,
+            clock.unix_timestamp as u64,
+        )?;
+    }
+
     let betting_account = &mut ctx.accounts.bet_account;
     let answer_account = &ctx.accounts.answer_account;
 
-    let correct_answer_key = market_account.correct_answer_key;
-    let betting_tokens = betting_account.tokens as u128;
-    let answer_key = betting_account.answer_key;
-    let finish_time = market_account.finish_time;
-
-    let mut percentage = 0;
-
-    if market_account.status == MarketStatus::Success
-        && betting_account.answer_key == correct_answer_key
-    {
-        let mut correct_answer_total_tokens: u128 = 0;
-        for answer in &answer_account.answers {
-            if answer.answer_key == correct_answer_key {
-                correct_answer_total_tokens = answer.answer_total_tokens as u128;
-                break;
-            }
-        }
+    let discount_bps = staking_discount_bps(
+        &ctx.accounts.fee_discount_tiers,
+        ctx.accounts.reward_stake_account.as_deref(),
+    )?;
+
+    let claim_amounts = settle_claim(market_account, answer_account, betting_account, discount_bps)?;
+
+    let vest_tokens = market_account.vesting_period > 0
+        && claim_amounts.receive_tokens > market_account.vesting_threshold;
+    let vest_reward = market_account.vesting_period > 0 && claim_amounts.reward_amount > 0;
+
+    if let Some(vesting_account) = ctx.accounts.vesting_account.as_deref_mut() {
+        // An empty vesting account could never be withdrawn from, or closed.
+        require!(
+            vest_tokens || vest_reward,
+            ProgramErrorCode::VestingAccountNotNeeded
+        );
 
-        let market_reward_base_tokens = market_account.market_reward_base_tokens as u128;
-        percentage = market_reward_base_tokens
-            .checked_mul(MAX_PERCENTAGE_BASIS_POINTS)
-            .and_then(|result| result.checked_div(correct_answer_total_tokens))
-            .ok_or(ProgramErrorCode::MathOperationError)?;
-    } else if market_account.status == MarketStatus::Adjourn {
-        percentage = MAX_PERCENTAGE_BASIS_POINTS;
-        let answer_exists = answer_account
-            .answers
-            .iter()
-            .any(|answer| answer.answer_key == answer_key);
-
-        if !answer_exists {
-            return Err(ProgramErrorCode::InvalidAnswerKey.into());
+        let clock = Clock::get()?;
+
+        vesting_account.bump = ctx.bumps.vesting_account.unwrap_or_default();
+        vesting_account.owner = receiver;
+        vesting_account.market_key = market_account.market_key;
+        vesting_account.start_time = clock.unix_timestamp as u64;
+        vesting_account.end_time = vesting_account
+            .start_time
+            .checked_add(market_account.vesting_period)
+            .ok_or(ProgramErrorCode::Overflow)?;
+        if vest_tokens {
+            vesting_account.bet_amount = claim_amounts.receive_tokens;
+            market_account.vesting_bet_tokens = market_account
+                .vesting_bet_tokens
+                .checked_add(vesting_account.bet_amount)
+                .ok_or(ProgramErrorCode::Overflow)?;
+        }
+        if vest_reward {
+            vesting_account.reward_amount = claim_amounts.reward_amount;
+            market_account.vesting_reward_tokens = market_account
+                .vesting_reward_tokens
+                .checked_add(vesting_account.reward_amount)
+                .ok_or(ProgramErrorCode::Overflow)?;
         }
-    }
 
-    let receive_tokens = betting_tokens
-        .checked_mul(percentage)
-        .and_then(|result| result.checked_div(MAX_PERCENTAGE_BASIS_POINTS))
-        .ok_or(ProgramErrorCode::MathOperationError)?;
+        emit!(ClaimVested {
+            receiver,
+            market_key: market_account.market_key,
+            bet_amount: vesting_account.bet_amount,
+            reward_amount: vesting_account.reward_amount,
+            end_time: vesting_account.end_time,
+        });
+    } else {
+        require!(
+            !vest_tokens && !vest_reward,
+            ProgramErrorCode::VestingAccountRequired
+        );
+    }
 
-    //dividend token to user
-    market_account.market_remain_tokens =
-        market_account.market_remain_tokens - receive_tokens as u64;
+    let receive_tokens = if vest_tokens {
+        0
+    } else {
+        claim_amounts.receive_tokens
+    };
 
     if receive_tokens > 0 {
         let bet_seeds: &[&[u8]] = &[
@@ -137,9 +215,6 @@ pub fn claim_token(ctx: Context<ClaimToken>) -> Result<()> {
             &[ctx.accounts.market_account.bump],
         ];
 
-        let amount_transfer_fee =
-            get_transfer_inverse_fee(ctx.accounts.bet_mint.clone(), receive_tokens as u64).unwrap();
-
         transfer_from_pool_vault_to_user(
             &ctx.accounts.vault_bet_token_account.to_account_info(),
             &ctx.accounts.user_bet_token_account.to_account_info(),
@@ -147,45 +222,103 @@ pub fn claim_token(ctx: Context<ClaimToken>) -> Result<()> {
             &ctx.accounts.market_account.to_account_info(),
             &ctx.accounts.token_program.to_account_info(),
             Some(&ctx.accounts.token_2022_program.to_account_info()),
-            amount_transfer_fee as u64,
+            receive_tokens,
             &[&bet_seeds],
         )?;
 
-        betting_account.tokens = 0;
         emit!(TokenClaimed {
-            receiver: ctx.accounts.voter.key(),
+            receiver,
             market_key: ctx.accounts.market_account.market_key,
             betting_key: betting_account.answer_key,
-            received_tokens: receive_tokens as u64,
+            received_tokens: receive_tokens,
         });
     }
 
-    let reward_seeds: &[&[u8]] = &[CONFIG_SEED.as_bytes(), &[ctx.accounts.config_account.bump]];
-
-    let reward_amount = calculate_reward_amount(
-        betting_account.tokens,
-        config_account.reward_apr,
-        betting_account.create_time,
-        finish_time,
-    )?;
+    let reward_amount = if vest_reward {
+        0
+    } else {
+        claim_amounts.reward_amount
+    };
 
     if reward_amount > 0 {
-        transfer_from_pool_vault_to_user(
-            &ctx.accounts.vault_reward_token_account.to_account_info(),
+        transfer_market_reward(
+            &ctx.accounts.vault_reward_token_account,
             &ctx.accounts.user_reward_token_account.to_account_info(),
             ctx.accounts.reward_mint.clone(),
-            &ctx.accounts.config_account.to_account_info(),
+            &ctx.accounts.market_account,
+            &ctx.accounts.config_account,
             &ctx.accounts.token_program.to_account_info(),
-            Some(&ctx.accounts.token_2022_program.to_account_info()),
+            &ctx.accounts.token_2022_program.to_account_info(),
             reward_amount,
-            &[&reward_seeds],
         )?;
 
         emit!(RewardClaimed {
-            receiver: ctx.accounts.voter.key(),
+            receiver,
             amount: reward_amount
         })
     }
 
     Ok(())
 }
+
+/// Service fee discount the owner of `reward_stake_account` gets under the
+/// tiers at `FEE_DISCOUNT_SEED`, which stay empty until `set_fee_discount_tiers`.
+pub(crate) fn staking_discount_bps(
+    fee_discount_tiers: &AccountInfo,
+    reward_stake_account: Option<&Account<RewardStakeAccount>>,
+) -> Result<u16> {
+    match reward_stake_account {
+        Some(reward_stake_account) if !fee_discount_tiers.data_is_empty() => {
+            let data = fee_discount_tiers.try_borrow_data()?;
+            Ok(FeeDiscountTiers::try_deserialize(&mut &data[..])?
+                .discount_for(reward_stake_account.amount))
+        }
+        _ => Ok(0),
+    }
+}
+
+/// Take a claim out of the market's remaining tokens and clear the bet.
+/// Returns what has to be paid out; the caller moves the tokens.
+pub(crate) fn settle_claim(
+    market_account: &mut MarketAccount,
+    answer_account: &AnswerAccount,
+    betting_account: &mut BettingAccount,
+    discount_bps: u16,
+) -> Result<ClaimAmounts> {
+    let claim_amounts =
+        calculate_claim_amounts(market_account, answer_account, betting_account, discount_bps)?;
+    book_claim(market_account, betting_account, &claim_amounts)?;
+
+    Ok(claim_amounts)
+}
+
+/// Book already computed `claim_amounts` against the market and the bet.
+pub(crate) fn book_claim(
+    market_account: &mut MarketAccount,
+    betting_account: &mut BettingAccount,
+    claim_amounts: &ClaimAmounts,
+) -> Result<()> {
+    //dividend token to user
+    market_account.market_remain_tokens = market_account
+        .market_remain_tokens
+        .checked_sub(claim_amounts.receive_tokens)
+        .and_then(|result| result.checked_sub(claim_amounts.service_fee))
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    // the service fee stays in the vault until `sweep_service_fees`
+    market_account.service_fee_accrued = market_account
+        .service_fee_accrued
+        .checked_add(claim_amounts.service_fee)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    if claim_amounts.receive_tokens > 0 || claim_amounts.service_fee > 0 {
+        betting_account.tokens = 0;
+    }
+
+    market_account.reward_paid = market_account
+        .reward_paid
+        .checked_add(claim_amounts.reward_amount)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use crate::helper::{transfer_from_pool_vault_to_user, transfer_market_reward};
+use crate::instructions::claim_token::{
+    settle_claim, staking_discount_bps, RewardClaimed, TokenClaimed,
+};
+use crate::{
+    constant::{BASIS_POINTS, CRANK_CLAIM_DELAY, CRANK_FEE_BASIS_POINTS},
+    error::ProgramErrorCode,
+    AnswerAccount, BettingAccount, ConfigAccount, MarketAccount, MarketStatus, RewardStakeAccount,
+    ANSWER_SEED, CONFIG_SEED, FEE_DISCOUNT_SEED, MARKET_SEED, REWARD_STAKE_SEED,
+};
+
+#[derive(Accounts)]
+pub struct CrankClaim<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    /// CHECK: wallet the bet belongs to, checked against `bet_account.voter`.
+    #[account(
+        mut,
+        constraint = bettor.key() == bet_account.voter @ ProgramErrorCode::Unauthorized
+    )]
+    pub bettor: UncheckedAccount<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = bet_mint,
+        associated_token::authority = bettor
+    )]
+    pub bettor_bet_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = bet_mint
+    )]
+    pub cranker_bet_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = market_account
+    )]
+    pub vault_bet_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = reward_mint,
+        associated_token::authority = bettor
+    )]
+    pub bettor_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// The market's reward escrow, see `transfer_market_reward`.
+    #[account(
+        mut,
+        token::mint = reward_mint,
+    )]
+    pub vault_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Success || market_account.status == MarketStatus::Adjourn @ ProgramErrorCode::CannotClaimToken,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+      constraint = market_account.vesting_period == 0 @ ProgramErrorCode::VestingNotSupported,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+        mut,
+        close = bettor,
+        constraint = bet_account.market_key == market_account.market_key @ ProgramErrorCode::CannotClaimToken,
+    )]
+    pub bet_account: Box<Account<'info, BettingAccount>>,
+    #[account(
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+    /// CHECK: service fee discount tiers, empty until `set_fee_discount_tiers`.
+    #[account(
+        seeds = [FEE_DISCOUNT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_discount_tiers: UncheckedAccount<'info>,
+    /// The bettor's locked reward tokens, which pick the discount tier.
+    #[account(
+        seeds = [REWARD_STAKE_SEED.as_bytes(), bettor.key().as_ref()],
+        bump = reward_stake_account.bump,
+    )]
+    pub reward_stake_account: Option<Box<Account<'info, RewardStakeAccount>>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ClaimCranked {
+    pub cranker: Pubkey,
+    pub receiver: Pubkey,
+    pub market_key: u64,
+    pub crank_fee: u64,
+}
+
+/// Push a resolved bet's payout to the bettor's ATA. Anyone may call it once
+/// `CRANK_CLAIM_DELAY` has passed since resolution, and keeps
+/// `CRANK_FEE_BASIS_POINTS` of the payout.
+pub fn crank_claim(ctx: Context<CrankClaim>) -> Result<()> {
+    let market_account = &mut ctx.accounts.market_account;
+
+    let resolve_time = if market_account.status == MarketStatus::Success {
+        market_account.success_time
+    } else {
+        market_account.adjourn_time
+    };
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp as u64 >= resolve_time.saturating_add(CRANK_CLAIM_DELAY),
+        ProgramErrorCode::CrankTooEarly
+    );
+
+    let discount_bps = staking_discount_bps(
+        &ctx.accounts.fee_discount_tiers,
+        ctx.accounts.reward_stake_account.as_deref(),
+    )?;
+    let claim_amounts = settle_claim(
+        market_account,
+        &ctx.accounts.answer_account,
+        &mut ctx.accounts.bet_account,
+        discount_bps,
+    )?;
+    let receive_tokens = claim_amounts.receive_tokens;
+
+    let crank_fee = receive_tokens
+        .checked_mul(CRANK_FEE_BASIS_POINTS)
+        .and_then(|result| result.checked_div(BASIS_POINTS as u64))
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    if receive_tokens > 0 {
+        let bet_seeds: &[&[u8]] = &[
+            MARKET_SEED.as_bytes(),
+            &market_account.market_key.to_le_bytes(),
+            &[market_account.bump],
+        ];
+
+        transfer_from_pool_vault_to_user(
+            &ctx.accounts.vault_bet_token_account.to_account_info(),
+            &ctx.accounts.bettor_bet_token_account.to_account_info(),
+            ctx.accounts.bet_mint.clone(),
+            &ctx.accounts.market_account.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&ctx.accounts.token_2022_program.to_account_info()),
+            receive_tokens - crank_fee,
+            &[&bet_seeds],
+        )?;
+
+        transfer_from_pool_vault_to_user(
+            &ctx.accounts.vault_bet_token_account.to_account_info(),
+            &ctx.accounts.cranker_bet_token_account.to_account_info(),
+            ctx.accounts.bet_mint.clone(),
+            &ctx.accounts.market_account.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&ctx.accounts.token_2022_program.to_account_info()),
+            crank_fee,
+            &[&bet_seeds],
+        )?;
+
+        emit!(TokenClaimed {
+            receiver: ctx.accounts.bettor.key(),
+            market_key: ctx.accounts.market_account.market_key,
+            betting_key: ctx.accounts.bet_account.answer_key,
+            received_tokens: receive_tokens - crank_fee,
+        });
+    }
+
+    let reward_amount = claim_amounts.reward_amount;
+
+    if reward_amount > 0 {
+        transfer_market_reward(
+            &ctx.accounts.vault_reward_token_account,
+            &ctx.accounts.bettor_reward_token_account.to_account_info(),
+            ctx.accounts.reward_mint.clone(),
+            &ctx.accounts.market_account,
+            &ctx.accounts.config_account,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.token_2022_program.to_account_info(),
+            reward_amount,
+        )?;
+
+        emit!(RewardClaimed {
+            receiver: ctx.accounts.bettor.key(),
+            amount: reward_amount
+        })
+    }
+
+    emit!(ClaimCranked {
+        cranker: ctx.accounts.cranker.key(),
+        receiver: ctx.accounts.bettor.key(),
+        market_key: ctx.accounts.market_account.market_key,
+        crank_fee,
+    });
+
+    Ok(())
+}
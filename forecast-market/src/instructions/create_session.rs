@@ -0,0 +1,90 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+
+use anchor_spl::token_interface::{Token2022, TokenAccount};
+
+use crate::helper::approve_delegate;
+use crate::{error::ProgramErrorCode, SessionAccount, MAX_SESSION_MARKETS, SESSION_SEED};
+
+#[derive(Accounts)]
+#[instruction(session_key: Pubkey)]
+pub struct CreateSession<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        token::authority = owner
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      init,
+      payer = owner,
+      space = SessionAccount::MAX_SIZE,
+      seeds = [SESSION_SEED.as_bytes(), owner.key().as_ref(), session_key.as_ref()],
+      bump,
+    )]
+    pub session_account: Box<Account<'info, SessionAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SessionCreated {
+    pub owner: Pubkey,
+    pub session_key: Pubkey,
+    pub spend_limit: u64,
+    pub expiry: u64,
+    pub allowed_markets: Vec<u64>,
+}
+
+pub fn create_session(
+    ctx: Context<CreateSession>,
+    session_key: Pubkey,
+    spend_limit: u64,
+    expiry: u64,
+    allowed_markets: Vec<u64>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        expiry > clock.unix_timestamp as u64,
+        ProgramErrorCode::SessionExpired
+    );
+    require!(
+        allowed_markets.len() <= MAX_SESSION_MARKETS,
+        ProgramErrorCode::SessionScopeExceeded
+    );
+
+    approve_delegate(
+        &ctx.accounts.owner_token_account.to_account_info(),
+        &ctx.accounts.session_account.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        spend_limit,
+    )?;
+
+    let session_account = ctx.accounts.session_account.deref_mut();
+    session_account.bump = ctx.bumps.session_account;
+    session_account.owner = ctx.accounts.owner.key();
+    session_account.session_key = session_key;
+    session_account.spend_limit = spend_limit;
+    session_account.spent = 0;
+    session_account.expiry = expiry;
+    session_account.allowed_markets = allowed_markets.clone();
+
+    emit!(SessionCreated {
+        owner: ctx.accounts.owner.key(),
+        session_key,
+        spend_limit,
+        expiry,
+        allowed_markets,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_user_to_pool_vault;
+use crate::instructions::MarketDisputed;
+use crate::{
+    error::ProgramErrorCode, MarketAccount, MarketStatus, ProposalStatus, ResolutionProposal,
+    PROPOSAL_BOND_SEED, RESOLUTION_PROPOSAL_SEED,
+};
+
+#[derive(Accounts)]
+pub struct DisputeProposal<'info> {
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Finished @ ProgramErrorCode::MarketNotFinished,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = disputer
+    )]
+    pub disputer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [RESOLUTION_PROPOSAL_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump = resolution_proposal.bump,
+        constraint = resolution_proposal.status == ProposalStatus::Proposed @ ProgramErrorCode::InvalidProposalStatus,
+    )]
+    pub resolution_proposal: Box<Account<'info, ResolutionProposal>>,
+    #[account(
+        mut,
+        seeds = [PROPOSAL_BOND_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump,
+    )]
+    pub bond_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[event]
+pub struct ProposalDisputed {
+    pub market_key: u64,
+    pub disputer: Pubkey,
+    pub answer_key: u64,
+}
+
+/// Match the proposer's bond to dispute its answer. The market waits for a
+/// final ruling in `rule_dispute`.
+pub fn dispute_proposal(ctx: Context<DisputeProposal>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        (clock.unix_timestamp as u64) < ctx.accounts.resolution_proposal.liveness_end,
+        ProgramErrorCode::LivenessEnded
+    );
+
+    transfer_from_user_to_pool_vault(
+        &ctx.accounts.disputer_token_account.to_account_info(),
+        &ctx.accounts.bond_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.disputer.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        ctx.accounts.resolution_proposal.bond_amount,
+    )?;
+
+    let resolution_proposal = ctx.accounts.resolution_proposal.deref_mut();
+
+    resolution_proposal.status = ProposalStatus::Disputed;
+    resolution_proposal.disputer = ctx.accounts.disputer.key();
+
+    let market_account = ctx.accounts.market_account.deref_mut();
+
+    market_account.status = MarketStatus::Disputed;
+
+    emit!(ProposalDisputed {
+        market_key: market_account.market_key,
+        disputer: resolution_proposal.disputer,
+        answer_key: resolution_proposal.answer_key,
+    });
+    emit!(MarketDisputed {
+        market_key: market_account.market_key,
+    });
+
+    Ok(())
+}
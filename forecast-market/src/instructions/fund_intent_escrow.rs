@@ -0,0 +1,83 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_user_to_pool_vault;
+use crate::{IntentNonceAccount, INTENT_ESCROW_SEED, INTENT_NONCE_SEED};
+
+#[derive(Accounts)]
+pub struct FundIntentEscrow<'info> {
+    /// Pays the rent of the voter's nonce and escrow accounts, usually the
+    /// relayer, so the voter only signs for the tokens.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub voter: Signer<'info>,
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = voter
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      init_if_needed,
+      payer = payer,
+      space = IntentNonceAccount::MAX_SIZE,
+      seeds = [INTENT_NONCE_SEED.as_bytes(), voter.key().as_ref()],
+      bump,
+    )]
+    pub nonce_account: Box<Account<'info, IntentNonceAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [INTENT_ESCROW_SEED.as_bytes(), voter.key().as_ref(), bet_mint.key().as_ref()],
+        bump,
+        token::mint = bet_mint,
+        token::authority = nonce_account
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct IntentEscrowFunded {
+    pub voter: Pubkey,
+    pub bet_mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Move tokens into the voter's intent escrow, which `bet_with_intent` pays
+/// relayed bets from.
+pub fn fund_intent_escrow(ctx: Context<FundIntentEscrow>, amount: u64) -> Result<()> {
+    let voter = ctx.accounts.voter.key();
+
+    let nonce_account = ctx.accounts.nonce_account.deref_mut();
+    nonce_account.bump = ctx.bumps.nonce_account;
+    nonce_account.voter = voter;
+
+    transfer_from_user_to_pool_vault(
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.voter.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        amount,
+    )?;
+
+    emit!(IntentEscrowFunded {
+        voter,
+        bet_mint: ctx.accounts.bet_mint.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::{has_transfer_fee, lmsr_funding_amount, transfer_from_user_to_pool_vault};
+use crate::{
+    error::ProgramErrorCode, AnswerAccount, LmsrAccount, MarketAccount, MarketStatus, MarketType,
+    OutcomeShares, ANSWER_SEED, LMSR_SEED, MAX_LMSR_OUTCOMES,
+};
+
+#[derive(Accounts)]
+pub struct FundLmsrMarket<'info> {
+    #[account(
+        mut,
+        constraint = (creator.key() == market_account.creator) @ ProgramErrorCode::Unauthorized
+    )]
+    pub creator: Signer<'info>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = bet_mint,
+        associated_token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Draft @ ProgramErrorCode::MarketNotDraft,
+      constraint = market_account.market_type == MarketType::Parimutuel @ ProgramErrorCode::InvalidMarketType,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+    #[account(
+      init,
+      payer = creator,
+      space = LmsrAccount::MAX_SIZE,
+      seeds = [LMSR_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub lmsr_account: Box<Account<'info, LmsrAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct LmsrMarketFunded {
+    pub market_key: u64,
+    pub liquidity_param: u64,
+    pub funding_tokens: u64,
+}
+
+pub fn fund_lmsr_market(ctx: Context<FundLmsrMarket>, liquidity_param: u64) -> Result<()> {
+    require!(liquidity_param > 0, ProgramErrorCode::InvalidLiquidityParam);
+
+    let answers = &ctx.accounts.answer_account.answers;
+    require!(answers.len() >= 2, ProgramErrorCode::NotEnoughAnswers);
+    require!(
+        answers.len() <= MAX_LMSR_OUTCOMES,
+        ProgramErrorCode::TooManyLmsrOutcomes
+    );
+    // Shares redeem 1:1 from the vault, so every trade has to arrive in full.
+    require!(
+        !has_transfer_fee(&ctx.accounts.bet_mint)?,
+        ProgramErrorCode::TransferFeeNotSupported
+    );
+
+    let funding_tokens = lmsr_funding_amount(answers.len(), liquidity_param)?;
+
+    transfer_from_user_to_pool_vault(
+        &ctx.accounts.creator_token_account.to_account_info(),
+        &ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        funding_tokens,
+    )?;
+
+    let lmsr_account = ctx.accounts.lmsr_account.deref_mut();
+    lmsr_account.bump = ctx.bumps.lmsr_account;
+    lmsr_account.market_key = ctx.accounts.market_account.market_key;
+    lmsr_account.liquidity_param = liquidity_param;
+    lmsr_account.funding_tokens = funding_tokens;
+    lmsr_account.outcomes = answers
+        .iter()
+        .map(|answer| OutcomeShares {
+            answer_key: answer.answer_key,
+            total_shares: 0,
+        })
+        .collect();
+
+    let market_account = ctx.accounts.market_account.deref_mut();
+    market_account.market_type = MarketType::Lmsr;
+    market_account.market_total_tokens = market_account
+        .market_total_tokens
+        .checked_add(funding_tokens)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    emit!(LmsrMarketFunded {
+        market_key: market_account.market_key,
+        liquidity_param,
+        funding_tokens,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn};
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_pool_vault_to_user;
+use crate::{
+    error::ProgramErrorCode, AnswerAccount, MarketAccount, OutcomeMintsAccount, ANSWER_SEED,
+    MARKET_SEED, OUTCOME_MINTS_SEED,
+};
+
+/// Remaining accounts: one `(outcome_mint, user_outcome_token_account)` pair
+/// per answer, in the order of `answer_account.answers`.
+#[derive(Accounts)]
+pub struct MergeCompleteSet<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+    #[account(
+      seeds = [OUTCOME_MINTS_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump = outcome_mints_account.bump,
+    )]
+    pub outcome_mints_account: Box<Account<'info, OutcomeMintsAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CompleteSetMerged {
+    pub voter: Pubkey,
+    pub market_key: u64,
+    pub amount: u64,
+}
+
+pub fn merge_complete_set<'info>(
+    ctx: Context<'_, '_, '_, 'info, MergeCompleteSet<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let market_key = ctx.accounts.market_account.market_key;
+    let answers = &ctx.accounts.answer_account.answers;
+    let outcome_mints_account = &ctx.accounts.outcome_mints_account;
+
+    require!(
+        !answers.is_empty() && ctx.remaining_accounts.len() == answers.len() * 2,
+        ProgramErrorCode::InvalidOutcomeAccounts
+    );
+
+    for (answer, accounts) in answers.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let outcome_mint = &accounts[0];
+        let user_outcome_token_account = &accounts[1];
+
+        require!(
+            outcome_mint.key() == outcome_mints_account.mint_address(answer.answer_key)?,
+            ProgramErrorCode::InvalidOutcomeAccounts
+        );
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: outcome_mint.to_account_info(),
+                    from: user_outcome_token_account.to_account_info(),
+                    authority: ctx.accounts.voter.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    let market_account = ctx.accounts.market_account.deref_mut();
+    market_account.outcome_collateral_tokens = market_account
+        .outcome_collateral_tokens
+        .checked_sub(amount)
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    let seeds: &[&[u8]] = &[
+        MARKET_SEED.as_bytes(),
+        &market_key.to_le_bytes(),
+        &[market_account.bump],
+    ];
+
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.vault_token_account.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.market_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        amount,
+        &[&seeds],
+    )?;
+
+    emit!(CompleteSetMerged {
+        voter: ctx.accounts.voter.key(),
+        market_key,
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::helper::write_migrated_account;
+use crate::instructions::AccountMigrated;
+use crate::{
+    error::ProgramErrorCode, read_legacy_account, stored_version, AnswerAccount, AnswerAccountV0,
+};
+
+#[derive(Accounts)]
+pub struct MigrateAnswer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: decoded in its unversioned layout by `read_legacy_account`.
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    pub answer_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The answer account only grows as far as its answers need, like it does
+/// when `set_answer_odds` publishes odds.
+pub fn migrate_answer(ctx: Context<MigrateAnswer>) -> Result<()> {
+    let account_info = ctx.accounts.answer_account.to_account_info();
+
+    let data = account_info.try_borrow_data()?;
+    let answer_account =
+        match stored_version(&data, &AnswerAccount::DISCRIMINATOR, AnswerAccount::VERSION)? {
+            0 => AnswerAccount::from(read_legacy_account::<AnswerAccountV0>(
+                &data,
+                &AnswerAccount::DISCRIMINATOR,
+            )?),
+            _ => return err!(ProgramErrorCode::AlreadyMigrated),
+        };
+    drop(data);
+
+    write_migrated_account(
+        &account_info,
+        &answer_account,
+        0,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(AccountMigrated {
+        account: account_info.key(),
+        version: AnswerAccount::VERSION,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::helper::write_migrated_account;
+use crate::instructions::AccountMigrated;
+use crate::{
+    error::ProgramErrorCode, read_legacy_account, stored_version, BettingAccount, BettingAccountV0,
+};
+
+#[derive(Accounts)]
+pub struct MigrateBetting<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: decoded in its unversioned layout by `read_legacy_account`.
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    pub bet_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_betting(ctx: Context<MigrateBetting>) -> Result<()> {
+    let account_info = ctx.accounts.bet_account.to_account_info();
+
+    let data = account_info.try_borrow_data()?;
+    let bet_account = match stored_version(
+        &data,
+        &BettingAccount::DISCRIMINATOR,
+        BettingAccount::VERSION,
+    )? {
+        0 => BettingAccount::from(read_legacy_account::<BettingAccountV0>(
+            &data,
+            &BettingAccount::DISCRIMINATOR,
+        )?),
+        _ => return err!(ProgramErrorCode::AlreadyMigrated),
+    };
+    drop(data);
+
+    write_migrated_account(
+        &account_info,
+        &bet_account,
+        BettingAccount::MAX_SIZE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(AccountMigrated {
+        account: account_info.key(),
+        version: BettingAccount::VERSION,
+    });
+
+    Ok(())
+}
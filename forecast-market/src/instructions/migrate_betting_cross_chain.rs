@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::helper::write_migrated_account;
+use crate::instructions::AccountMigrated;
+use crate::{
+    error::ProgramErrorCode, read_legacy_account, stored_version, BettingCrossChainAccount,
+    BettingCrossChainAccountV0,
+};
+
+#[derive(Accounts)]
+pub struct MigrateBettingCrossChain<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: decoded in its unversioned layout by `read_legacy_account`.
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    pub bet_cross_chain_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_betting_cross_chain(ctx: Context<MigrateBettingCrossChain>) -> Result<()> {
+    let account_info = ctx.accounts.bet_cross_chain_account.to_account_info();
+
+    let data = account_info.try_borrow_data()?;
+    let bet_cross_chain_account = match stored_version(
+        &data,
+        &BettingCrossChainAccount::DISCRIMINATOR,
+        BettingCrossChainAccount::VERSION,
+    )? {
+        0 => BettingCrossChainAccount::from(read_legacy_account::<BettingCrossChainAccountV0>(
+            &data,
+            &BettingCrossChainAccount::DISCRIMINATOR,
+        )?),
+        _ => return err!(ProgramErrorCode::AlreadyMigrated),
+    };
+    drop(data);
+
+    write_migrated_account(
+        &account_info,
+        &bet_cross_chain_account,
+        BettingCrossChainAccount::MAX_SIZE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(AccountMigrated {
+        account: account_info.key(),
+        version: BettingCrossChainAccount::VERSION,
+    });
+
+    Ok(())
+}
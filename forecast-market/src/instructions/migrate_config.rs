@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::helper::write_migrated_account;
+use crate::{
+    error::ProgramErrorCode, read_legacy_account, stored_version, ConfigAccount, ConfigAccountV0,
+    ConfigTimelockAccount, CONFIG_SEED, CONFIG_TIMELOCK_SEED,
+};
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: decoded in its unversioned layout by `read_legacy_account`.
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump,
+    )]
+    pub config_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ConfigTimelockAccount::LEN,
+        seeds = [CONFIG_TIMELOCK_SEED.as_bytes()],
+        bump,
+    )]
+    pub config_timelock_account: Account<'info, ConfigTimelockAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub version: u8,
+}
+
+/// Rewrite the config in the current versioned layout and set up its config
+/// timelock. Anyone may migrate an account; the payer covers any extra rent.
+pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let account_info = ctx.accounts.config_account.to_account_info();
+
+    let data = account_info.try_borrow_data()?;
+    let config_account =
+        match stored_version(&data, &ConfigAccount::DISCRIMINATOR, ConfigAccount::VERSION)? {
+            0 => ConfigAccount::from(read_legacy_account::<ConfigAccountV0>(
+                &data,
+                &ConfigAccount::DISCRIMINATOR,
+            )?),
+            _ => return err!(ProgramErrorCode::AlreadyMigrated),
+        };
+    drop(data);
+
+    write_migrated_account(
+        &account_info,
+        &config_account,
+        ConfigAccount::LEN,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    ctx.accounts
+        .config_timelock_account
+        .init(ctx.bumps.config_timelock_account);
+
+    emit!(AccountMigrated {
+        account: account_info.key(),
+        version: ConfigAccount::VERSION,
+    });
+
+    Ok(())
+}
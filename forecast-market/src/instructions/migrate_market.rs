@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::helper::write_migrated_account;
+use crate::instructions::AccountMigrated;
+use crate::{
+    error::ProgramErrorCode, read_legacy_account, stored_version, ConfigAccount, MarketAccount,
+    MarketAccountV0, MarketAccountV1, MarketAccountV2, CONFIG_SEED,
+};
+
+#[derive(Accounts)]
+pub struct MigrateMarket<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Migrated first, unversioned approved markets take their APR from it.
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    /// CHECK: decoded in its stored layout by `read_legacy_account`.
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    pub market_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrade a market from any older layout to the current one.
+pub fn migrate_market(ctx: Context<MigrateMarket>) -> Result<()> {
+    let account_info = ctx.accounts.market_account.to_account_info();
+
+    let data = account_info.try_borrow_data()?;
+    let market_account =
+        match stored_version(&data, &MarketAccount::DISCRIMINATOR, MarketAccount::VERSION)? {
+            0 => read_legacy_account::<MarketAccountV0>(&data, &MarketAccount::DISCRIMINATOR)?
+                .migrate(ctx.accounts.config_account.reward_apr),
+            1 => MarketAccount::from(read_legacy_account::<MarketAccountV1>(
+                &data,
+                &MarketAccount::DISCRIMINATOR,
+            )?),
+            2 => MarketAccount::from(read_legacy_account::<MarketAccountV2>(
+                &data,
+                &MarketAccount::DISCRIMINATOR,
+            )?),
+            _ => return err!(ProgramErrorCode::AlreadyMigrated),
+        };
+    drop(data);
+
+    write_migrated_account(
+        &account_info,
+        &market_account,
+        MarketAccount::LEN,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(AccountMigrated {
+        account: account_info.key(),
+        version: MarketAccount::VERSION,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, MintTo};
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_user_to_pool_vault;
+use crate::{
+    error::ProgramErrorCode, AnswerAccount, MarketAccount, MarketStatus, OutcomeMintsAccount,
+    ANSWER_SEED, MARKET_SEED, OUTCOME_MINTS_SEED,
+};
+
+/// Remaining accounts: one `(outcome_mint, user_outcome_token_account)` pair
+/// per answer, in the order of `answer_account.answers`.
+#[derive(Accounts)]
+pub struct MintCompleteSet<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = voter,
+        associated_token::mint = bet_mint,
+        associated_token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+    #[account(
+      seeds = [OUTCOME_MINTS_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump = outcome_mints_account.bump,
+    )]
+    pub outcome_mints_account: Box<Account<'info, OutcomeMintsAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CompleteSetMinted {
+    pub voter: Pubkey,
+    pub market_key: u64,
+    pub amount: u64,
+}
+
+pub fn mint_complete_set<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintCompleteSet<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let market_key = ctx.accounts.market_account.market_key;
+    let answers = &ctx.accounts.answer_account.answers;
+    let outcome_mints_account = &ctx.accounts.outcome_mints_account;
+
+    require!(
+        !answers.is_empty() && ctx.remaining_accounts.len() == answers.len() * 2,
+        ProgramErrorCode::InvalidOutcomeAccounts
+    );
+
+    let vault_amount = ctx.accounts.vault_token_account.amount;
+    transfer_from_user_to_pool_vault(
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.voter.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        amount,
+    )?;
+
+    // Sets are backed by what the vault got, net of any transfer fee.
+    ctx.accounts.vault_token_account.reload()?;
+    let received = ctx
+        .accounts
+        .vault_token_account
+        .amount
+        .checked_sub(vault_amount)
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    let seeds: &[&[u8]] = &[
+        MARKET_SEED.as_bytes(),
+        &market_key.to_le_bytes(),
+        &[ctx.accounts.market_account.bump],
+    ];
+
+    for (answer, accounts) in answers.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let outcome_mint = &accounts[0];
+        let user_outcome_token_account = &accounts[1];
+
+        require!(
+            outcome_mint.key() == outcome_mints_account.mint_address(answer.answer_key)?,
+            ProgramErrorCode::InvalidOutcomeAccounts
+        );
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: outcome_mint.to_account_info(),
+                    to: user_outcome_token_account.to_account_info(),
+                    authority: ctx.accounts.market_account.to_account_info(),
+                },
+                &[&seeds],
+            ),
+            received,
+        )?;
+    }
+
+    let market_account = ctx.accounts.market_account.deref_mut();
+    market_account.outcome_collateral_tokens = market_account
+        .outcome_collateral_tokens
+        .checked_add(received)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    emit!(CompleteSetMinted {
+        voter: ctx.accounts.voter.key(),
+        market_key,
+        amount: received,
+    });
+
+    Ok(())
+}
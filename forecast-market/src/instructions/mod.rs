@@ -8,4 +8,112 @@ pub mod bet_cross_chain;
 pub use bet_cross_chain::*;
 
 pub mod claim_token;
-pub use claim_token::*;
\ No newline at end of file
+pub use claim_token::*;
+
+pub mod quote_bet;
+pub use quote_bet::*;
+
+pub mod quote_claim;
+pub use quote_claim::*;
+
+pub mod fund_lmsr_market;
+pub use fund_lmsr_market::*;
+
+pub mod buy_shares;
+pub use buy_shares::*;
+
+pub mod sell_shares;
+pub use sell_shares::*;
+
+pub mod claim_shares;
+pub use claim_shares::*;
+
+pub mod mint_complete_set;
+pub use mint_complete_set::*;
+
+pub mod merge_complete_set;
+pub use merge_complete_set::*;
+
+pub mod redeem_outcome_tokens;
+pub use redeem_outcome_tokens::*;
+
+pub mod bet_with_intent;
+pub use bet_with_intent::*;
+
+pub mod fund_intent_escrow;
+pub use fund_intent_escrow::*;
+
+pub mod withdraw_intent_escrow;
+pub use withdraw_intent_escrow::*;
+
+pub mod stake_reward;
+pub use stake_reward::*;
+
+pub mod unstake_reward;
+pub use unstake_reward::*;
+
+pub mod create_session;
+pub use create_session::*;
+
+pub mod revoke_session;
+pub use revoke_session::*;
+
+pub mod claim_many;
+pub use claim_many::*;
+
+pub mod crank_claim;
+pub use crank_claim::*;
+
+pub mod claim_cross_chain;
+pub use claim_cross_chain::*;
+
+pub mod withdraw_vested;
+pub use withdraw_vested::*;
+
+pub mod withdraw_fees;
+pub use withdraw_fees::*;
+
+pub mod open_fee_ledger;
+pub use open_fee_ledger::*;
+
+pub mod sweep_service_fees;
+pub use sweep_service_fees::*;
+
+pub mod attest_resolution;
+pub use attest_resolution::*;
+
+pub mod propose_resolution;
+pub use propose_resolution::*;
+
+pub mod dispute_proposal;
+pub use dispute_proposal::*;
+
+pub mod settle_proposal;
+pub use settle_proposal::*;
+
+pub mod open_resolution_vote;
+pub use open_resolution_vote::*;
+
+pub mod cast_resolution_vote;
+pub use cast_resolution_vote::*;
+
+pub mod settle_resolution_vote;
+pub use settle_resolution_vote::*;
+
+pub mod withdraw_resolution_vote;
+pub use withdraw_resolution_vote::*;
+
+pub mod migrate_config;
+pub use migrate_config::*;
+
+pub mod migrate_market;
+pub use migrate_market::*;
+
+pub mod migrate_answer;
+pub use migrate_answer::*;
+
+pub mod migrate_betting;
+pub use migrate_betting::*;
+
+pub mod migrate_betting_cross_chain;
+pub use migrate_betting_cross_chain::*;
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{FeeLedger, FEE_LEDGER_SEED};
+
+#[derive(Accounts)]
+#[instruction(recipient: Pubkey)]
+pub struct OpenFeeLedger<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        space = FeeLedger::MAX_SIZE,
+        seeds = [FEE_LEDGER_SEED.as_bytes(), recipient.as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub fee_ledger: Box<Account<'info, FeeLedger>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create `recipient`'s fee ledger for `mint`, so it can be credited by a
+/// revenue split at resolution.
+pub fn open_fee_ledger(ctx: Context<OpenFeeLedger>, recipient: Pubkey) -> Result<()> {
+    ctx.accounts
+        .fee_ledger
+        .init(ctx.bumps.fee_ledger, recipient, ctx.accounts.mint.key());
+
+    Ok(())
+}
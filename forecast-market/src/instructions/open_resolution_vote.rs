@@ -0,0 +1,82 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, DisputeTally, MarketAccount, MarketStatus,
+    OracleConfig, CONFIG_SEED, DISPUTE_TALLY_SEED, ORACLE_CONFIG_SEED, VOTE_LOCK_SEED,
+};
+
+#[derive(Accounts)]
+pub struct OpenResolutionVote<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, ConfigAccount>>,
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED.as_bytes()],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+    #[account(
+      constraint = market_account.status == MarketStatus::Disputed @ ProgramErrorCode::MarketNotDisputed,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+        address = config_account.reward_mint @ ProgramErrorCode::InvalidRewardMint
+    )]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        space = DisputeTally::MAX_SIZE,
+        seeds = [DISPUTE_TALLY_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump,
+    )]
+    pub dispute_tally: Box<Account<'info, DisputeTally>>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [VOTE_LOCK_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = dispute_tally
+    )]
+    pub vote_lock_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ResolutionVoteOpened {
+    pub market_key: u64,
+    pub vote_end: u64,
+}
+
+/// Put a disputed market to a vote of reward token holders.
+pub fn open_resolution_vote(ctx: Context<OpenResolutionVote>) -> Result<()> {
+    let clock = Clock::get()?;
+    let vote_end = (clock.unix_timestamp as u64)
+        .checked_add(ctx.accounts.oracle_config.vote_period)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let dispute_tally = ctx.accounts.dispute_tally.deref_mut();
+
+    dispute_tally.bump = ctx.bumps.dispute_tally;
+    dispute_tally.market_key = ctx.accounts.market_account.market_key;
+    dispute_tally.vote_end = vote_end;
+
+    emit!(ResolutionVoteOpened {
+        market_key: dispute_tally.market_key,
+        vote_end,
+    });
+
+    Ok(())
+}
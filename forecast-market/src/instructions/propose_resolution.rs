@@ -0,0 +1,127 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_user_to_pool_vault;
+use crate::{
+    error::ProgramErrorCode, AnswerAccount, MarketAccount, MarketStatus, OracleConfig,
+    ProposalStatus, ResolutionProposal, ANSWER_SEED, ORACLE_CONFIG_SEED, PROPOSAL_BOND_SEED,
+    RESOLUTION_PROPOSAL_SEED,
+};
+
+#[derive(Accounts)]
+pub struct ProposeResolution<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED.as_bytes()],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+    #[account(
+      constraint = market_account.status == MarketStatus::Finished @ ProgramErrorCode::MarketNotFinished,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+      constraint = market_account.resolver_committee == Pubkey::default() @ ProgramErrorCode::CommitteeMarket,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = proposer
+    )]
+    pub proposer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = proposer,
+        space = ResolutionProposal::LEN,
+        seeds = [RESOLUTION_PROPOSAL_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump,
+    )]
+    pub resolution_proposal: Box<Account<'info, ResolutionProposal>>,
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        seeds = [PROPOSAL_BOND_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump,
+        token::mint = bet_mint,
+        token::authority = resolution_proposal
+    )]
+    pub bond_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ResolutionProposed {
+    pub market_key: u64,
+    pub proposer: Pubkey,
+    pub answer_key: u64,
+    pub bond_amount: u64,
+    pub liveness_end: u64,
+}
+
+/// Propose the answer of a finished market against a bond in `bet_mint`.
+/// Markets with a resolver committee resolve through it instead. Undisputed,
+/// a proposal can be settled with `settle_proposal` once the liveness window
+/// has passed.
+pub fn propose_resolution(ctx: Context<ProposeResolution>, answer_key: u64) -> Result<()> {
+    require!(
+        ctx.accounts
+            .answer_account
+            .answers
+            .iter()
+            .any(|answer| answer.answer_key == answer_key),
+        ProgramErrorCode::MarketDoesNotContainAnswerKey
+    );
+
+    let oracle_config = &ctx.accounts.oracle_config;
+    let bond_amount = oracle_config.bond_amount(ctx.accounts.market_account.market_total_tokens)?;
+    require!(bond_amount > 0, ProgramErrorCode::InvalidBondAmount);
+
+    transfer_from_user_to_pool_vault(
+        &ctx.accounts.proposer_token_account.to_account_info(),
+        &ctx.accounts.bond_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.proposer.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        bond_amount,
+    )?;
+
+    let clock = Clock::get()?;
+    let liveness_end = (clock.unix_timestamp as u64)
+        .checked_add(oracle_config.liveness)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let resolution_proposal = ctx.accounts.resolution_proposal.deref_mut();
+
+    resolution_proposal.bump = ctx.bumps.resolution_proposal;
+    resolution_proposal.market_key = ctx.accounts.market_account.market_key;
+    resolution_proposal.proposer = ctx.accounts.proposer.key();
+    resolution_proposal.answer_key = answer_key;
+    resolution_proposal.bond_amount = bond_amount;
+    resolution_proposal.liveness_end = liveness_end;
+    resolution_proposal.status = ProposalStatus::Proposed;
+
+    emit!(ResolutionProposed {
+        market_key: resolution_proposal.market_key,
+        proposer: resolution_proposal.proposer,
+        answer_key,
+        bond_amount,
+        liveness_end,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::helper::{
+    calculate_fixed_odds_payout, calculate_market_fees, calculate_receive_tokens,
+};
+use crate::{
+    constant::MAX_PERCENTAGE_BASIS_POINTS, error::ProgramErrorCode, AnswerAccount, MarketAccount,
+    MarketStatus, MarketType, ANSWER_SEED,
+};
+
+#[derive(Accounts)]
+pub struct QuoteBet<'info> {
+    #[account(
+      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved,
+      constraint = market_account.market_type != MarketType::Lmsr @ ProgramErrorCode::InvalidMarketType,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BetQuote {
+    pub answer_key: u64,
+    pub amount: u64,
+    pub payout: u64,
+    pub creator_fee: u64,
+    pub service_fee: u64,
+}
+
+/// Payout of a hypothetical `amount` bet on `answer_key` if the market
+/// resolved to that answer right after it was placed.
+pub fn quote_bet(ctx: Context<QuoteBet>, answer_key: u64, amount: u64) -> Result<()> {
+    let market_account = &ctx.accounts.market_account;
+    let answer_account = &ctx.accounts.answer_account;
+
+    let answer = answer_account
+        .answers
+        .iter()
+        .find(|answer| answer.answer_key == answer_key)
+        .ok_or(ProgramErrorCode::AnswerNotExists)?;
+
+    if market_account.market_type == MarketType::FixedOdds {
+        let answer_odds = answer_account
+            .odds
+            .iter()
+            .find(|answer_odds| answer_odds.answer_key == answer_key)
+            .ok_or(ProgramErrorCode::OddsNotSet)?;
+
+        let quote = BetQuote {
+            answer_key,
+            amount,
+            payout: calculate_fixed_odds_payout(amount, answer_odds.odds)?,
+            creator_fee: 0,
+            service_fee: 0,
+        };
+
+        set_return_data(&quote.try_to_vec()?);
+
+        return Ok(());
+    }
+
+    let market_total_tokens = market_account
+        .market_total_tokens
+        .checked_add(amount)
+        .ok_or(ProgramErrorCode::Overflow)?;
+    let answer_total_tokens = answer
+        .answer_total_tokens
+        .checked_add(amount)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let fees = calculate_market_fees(market_account, market_total_tokens)?;
+
+    let percentage = (fees.reward_base_tokens as u128)
+        .checked_mul(MAX_PERCENTAGE_BASIS_POINTS)
+        .and_then(|result| result.checked_div(answer_total_tokens as u128))
+        .unwrap_or(0);
+
+    let quote = BetQuote {
+        answer_key,
+        amount,
+        payout: calculate_receive_tokens(amount, percentage)?,
+        creator_fee: fees.creator_fee,
+        service_fee: fees.service_fee,
+    };
+
+    set_return_data(&quote.try_to_vec()?);
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::helper::calculate_claim_amounts;
+use crate::{
+    error::ProgramErrorCode, AnswerAccount, BettingAccount, MarketAccount, MarketStatus,
+    ANSWER_SEED,
+};
+
+#[derive(Accounts)]
+pub struct QuoteClaim<'info> {
+    #[account(
+      constraint = market_account.status == MarketStatus::Success || market_account.status == MarketStatus::Adjourn @ ProgramErrorCode::CannotClaimToken,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+    #[account(
+      constraint = bet_account.market_key == market_account.market_key @ ProgramErrorCode::InvalidAnswerKey,
+    )]
+    pub bet_account: Box<Account<'info, BettingAccount>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimQuote {
+    pub receive_tokens: u64,
+    pub service_fee: u64,
+    pub reward_amount: u64,
+}
+
+/// Amounts `claim_token` would pay out for `bet_account` right now, before
+/// any staking discount on the service fee.
+pub fn quote_claim(ctx: Context<QuoteClaim>) -> Result<()> {
+    let claim_amounts = calculate_claim_amounts(
+        &ctx.accounts.market_account,
+        &ctx.accounts.answer_account,
+        &ctx.accounts.bet_account,
+        0,
+    )?;
+
+    let quote = ClaimQuote {
+        receive_tokens: claim_amounts.receive_tokens,
+        service_fee: claim_amounts.service_fee,
+        reward_amount: claim_amounts.reward_amount,
+    };
+
+    set_return_data(&quote.try_to_vec()?);
+
+    Ok(())
+}
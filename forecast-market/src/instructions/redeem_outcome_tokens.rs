@@ -0,0 +1,166 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn};
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_pool_vault_to_user;
+use crate::{
+    error::ProgramErrorCode, AnswerAccount, MarketAccount, MarketStatus, OutcomeMintsAccount,
+    ANSWER_SEED, MARKET_SEED, OUTCOME_MINTS_SEED, OUTCOME_MINT_SEED,
+};
+
+/// Remaining accounts, for an adjourned market only: every outcome mint, in
+/// the order of `answer_account.answers`.
+#[derive(Accounts)]
+#[instruction(answer_key: u64)]
+pub struct RedeemOutcomeTokens<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [OUTCOME_MINT_SEED.as_bytes(), &market_account.market_key.to_le_bytes(), &answer_key.to_le_bytes()],
+        bump,
+    )]
+    pub outcome_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        mut,
+        token::mint = outcome_mint,
+        token::authority = voter
+    )]
+    pub user_outcome_token_account: Box<Account<'info, token::TokenAccount>>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Success || market_account.status == MarketStatus::Adjourn @ ProgramErrorCode::CannotClaimToken,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      seeds = [ANSWER_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+    #[account(
+      seeds = [OUTCOME_MINTS_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump = outcome_mints_account.bump,
+    )]
+    pub outcome_mints_account: Box<Account<'info, OutcomeMintsAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct OutcomeTokensRedeemed {
+    pub receiver: Pubkey,
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub amount: u64,
+    pub received_tokens: u64,
+}
+
+/// Winning outcome tokens redeem 1:1. When the market is adjourned every
+/// outcome token is worth an equal share of the remaining collateral, so the
+/// last tokens redeemed take whatever rounding left behind.
+pub fn redeem_outcome_tokens<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RedeemOutcomeTokens<'info>>,
+    answer_key: u64,
+    amount: u64,
+) -> Result<()> {
+    let receive_tokens = if ctx.accounts.market_account.status == MarketStatus::Success {
+        require!(
+            answer_key == ctx.accounts.market_account.correct_answer_key,
+            ProgramErrorCode::AnswerKeyNotRight
+        );
+        amount
+    } else {
+        let answers = &ctx.accounts.answer_account.answers;
+        require!(
+            ctx.remaining_accounts.len() == answers.len(),
+            ProgramErrorCode::InvalidOutcomeAccounts
+        );
+
+        let mut outstanding_tokens: u64 = 0;
+        for (answer, outcome_mint) in answers.iter().zip(ctx.remaining_accounts) {
+            require!(
+                outcome_mint.key()
+                    == ctx.accounts.outcome_mints_account.mint_address(answer.answer_key)?,
+                ProgramErrorCode::InvalidOutcomeAccounts
+            );
+            let outcome_mint = Account::<token::Mint>::try_from(outcome_mint)?;
+            outstanding_tokens = outstanding_tokens
+                .checked_add(outcome_mint.supply)
+                .ok_or(ProgramErrorCode::Overflow)?;
+        }
+
+        let receive_tokens = (amount as u128)
+            .checked_mul(ctx.accounts.market_account.outcome_collateral_tokens as u128)
+            .and_then(|result| result.checked_div(outstanding_tokens as u128))
+            .ok_or(ProgramErrorCode::MathOperationError)?;
+        u64::try_from(receive_tokens).map_err(|_| ProgramErrorCode::MathOperationError)?
+    };
+
+    let market_account = ctx.accounts.market_account.deref_mut();
+    require!(receive_tokens > 0, ProgramErrorCode::NothingToRedeem);
+
+    market_account.outcome_collateral_tokens = market_account
+        .outcome_collateral_tokens
+        .checked_sub(receive_tokens)
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.outcome_mint.to_account_info(),
+                from: ctx.accounts.user_outcome_token_account.to_account_info(),
+                authority: ctx.accounts.voter.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let seeds: &[&[u8]] = &[
+        MARKET_SEED.as_bytes(),
+        &market_account.market_key.to_le_bytes(),
+        &[market_account.bump],
+    ];
+
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.vault_token_account.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.market_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        receive_tokens,
+        &[&seeds],
+    )?;
+
+    emit!(OutcomeTokensRedeemed {
+        receiver: ctx.accounts.voter.key(),
+        market_key: ctx.accounts.market_account.market_key,
+        answer_key,
+        amount,
+        received_tokens: receive_tokens,
+    });
+
+    Ok(())
+}
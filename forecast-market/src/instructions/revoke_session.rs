@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+
+use anchor_spl::token_interface::{Token2022, TokenAccount};
+
+use crate::helper::revoke_delegate;
+use crate::{error::ProgramErrorCode, SessionAccount};
+
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        token::authority = owner
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      close = owner,
+      constraint = session_account.owner == owner.key() @ ProgramErrorCode::Unauthorized,
+    )]
+    pub session_account: Box<Account<'info, SessionAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[event]
+pub struct SessionRevoked {
+    pub owner: Pubkey,
+    pub session_key: Pubkey,
+}
+
+pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+    // Only drop the delegation if it still belongs to this session.
+    if ctx.accounts.owner_token_account.delegate
+        == Some(ctx.accounts.session_account.key()).into()
+    {
+        revoke_delegate(
+            &ctx.accounts.owner_token_account.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&ctx.accounts.token_2022_program.to_account_info()),
+        )?;
+    }
+
+    emit!(SessionRevoked {
+        owner: ctx.accounts.owner.key(),
+        session_key: ctx.accounts.session_account.session_key,
+    });
+
+    Ok(())
+}
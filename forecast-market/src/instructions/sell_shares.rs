@@ -0,0 +1,127 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::{lmsr_sell_proceeds, transfer_from_pool_vault_to_user};
+use crate::{
+    error::ProgramErrorCode, LmsrAccount, MarketAccount, MarketStatus, MarketType,
+    SharePositionAccount, LMSR_SEED, MARKET_SEED, SHARE_POSITION_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(answer_key: u64)]
+pub struct SellShares<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = bet_mint,
+        associated_token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved,
+      constraint = market_account.market_type == MarketType::Lmsr @ ProgramErrorCode::InvalidMarketType,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+      mut,
+      seeds = [LMSR_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+      bump = lmsr_account.bump,
+    )]
+    pub lmsr_account: Box<Account<'info, LmsrAccount>>,
+    #[account(
+      mut,
+      seeds = [SHARE_POSITION_SEED.as_bytes(), voter.key().as_ref(), &market_account.market_key.to_le_bytes(), &answer_key.to_le_bytes()],
+      bump = position_account.bump,
+    )]
+    pub position_account: Box<Account<'info, SharePositionAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SharesSold {
+    pub voter: Pubkey,
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub shares: u64,
+    pub proceeds: u64,
+}
+
+pub fn sell_shares(
+    ctx: Context<SellShares>,
+    answer_key: u64,
+    shares: u64,
+    min_proceeds: u64,
+) -> Result<()> {
+    let position_account = ctx.accounts.position_account.deref_mut();
+    require!(
+        position_account.shares >= shares,
+        ProgramErrorCode::InsufficientShares
+    );
+
+    let lmsr_account = ctx.accounts.lmsr_account.deref_mut();
+    let index = lmsr_account
+        .outcome_index(answer_key)
+        .ok_or(ProgramErrorCode::AnswerNotExists)?;
+
+    let proceeds = lmsr_sell_proceeds(
+        &lmsr_account.total_shares(),
+        index,
+        shares,
+        lmsr_account.liquidity_param,
+    )?;
+    require!(proceeds >= min_proceeds, ProgramErrorCode::SlippageExceeded);
+
+    lmsr_account.outcomes[index].total_shares -= shares;
+    position_account.shares -= shares;
+
+    let market_account = ctx.accounts.market_account.deref_mut();
+    market_account.market_total_tokens = market_account
+        .market_total_tokens
+        .checked_sub(proceeds)
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    let seeds: &[&[u8]] = &[
+        MARKET_SEED.as_bytes(),
+        &market_account.market_key.to_le_bytes(),
+        &[market_account.bump],
+    ];
+
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.vault_token_account.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.market_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        proceeds,
+        &[&seeds],
+    )?;
+
+    emit!(SharesSold {
+        voter: ctx.accounts.voter.key(),
+        market_key: ctx.accounts.market_account.market_key,
+        answer_key,
+        shares,
+        proceeds,
+    });
+
+    Ok(())
+}
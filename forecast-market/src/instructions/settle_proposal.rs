@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::helper::transfer_from_pool_vault_to_user;
+use crate::instructions::admin::sucess_market::*;
+use crate::{
+    error::ProgramErrorCode, MarketStatus, OracleConfig, ProposalStatus, ResolutionProposal,
+    CONFIG_SEED, ORACLE_CONFIG_SEED, PROPOSAL_BOND_SEED, RESOLUTION_PROPOSAL_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SettleProposal<'info> {
+    /// `success.owner` is whoever settles the proposal and pays for the
+    /// fee accounts.
+    #[account(
+      constraint = success.market_account.status == MarketStatus::Finished @ ProgramErrorCode::MarketNotFinished,
+    )]
+    pub success: SuccessMarket<'info>,
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED.as_bytes()],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [RESOLUTION_PROPOSAL_SEED.as_bytes(), &success.market_account.market_key.to_le_bytes()],
+        bump = resolution_proposal.bump,
+        constraint = resolution_proposal.status == ProposalStatus::Proposed @ ProgramErrorCode::InvalidProposalStatus,
+    )]
+    pub resolution_proposal: Box<Account<'info, ResolutionProposal>>,
+    /// CHECK: checked against `resolution_proposal.proposer`, receives its rent.
+    #[account(
+        mut,
+        address = resolution_proposal.proposer @ ProgramErrorCode::Unauthorized
+    )]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        token::mint = success.bet_mint,
+        token::authority = proposer
+    )]
+    pub proposer_bet_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [PROPOSAL_BOND_SEED.as_bytes(), &success.market_account.market_key.to_le_bytes()],
+        bump,
+    )]
+    pub bond_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        address = success.config_account.reward_mint @ ProgramErrorCode::InvalidRewardMint
+    )]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = proposer
+    )]
+    pub proposer_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = success.config_account
+    )]
+    pub vault_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+#[event]
+pub struct ProposalSettled {
+    pub market_key: u64,
+    pub proposer: Pubkey,
+    pub answer_key: u64,
+    pub bond_amount: u64,
+    pub reward_amount: u64,
+}
+
+/// Resolve the market with an undisputed proposal once its liveness window
+/// has passed. The proposer gets the bond back plus `proposal_reward`.
+pub fn settle_proposal<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettleProposal<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let resolution_proposal = &ctx.accounts.resolution_proposal;
+
+    require!(
+        (clock.unix_timestamp as u64) >= resolution_proposal.liveness_end,
+        ProgramErrorCode::LivenessNotEnded
+    );
+
+    let market_key = resolution_proposal.market_key;
+    let answer_key = resolution_proposal.answer_key;
+    let bond_amount = resolution_proposal.bond_amount;
+    let proposal_bump = resolution_proposal.bump;
+
+    resolve_market(
+        &mut ctx.accounts.success,
+        &ctx.bumps.success,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        answer_key,
+    )?;
+
+    let success = &ctx.accounts.success;
+    let proposal_seeds: &[&[u8]] = &[
+        RESOLUTION_PROPOSAL_SEED.as_bytes(),
+        &market_key.to_le_bytes(),
+        &[proposal_bump],
+    ];
+
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.bond_token_account.to_account_info(),
+        &ctx.accounts.proposer_bet_token_account.to_account_info(),
+        success.bet_mint.clone(),
+        &ctx.accounts.resolution_proposal.to_account_info(),
+        &success.token_program.to_account_info(),
+        Some(&success.token_2022_program.to_account_info()),
+        bond_amount,
+        &[&proposal_seeds],
+    )?;
+
+    let reward_amount = ctx
+        .accounts
+        .oracle_config
+        .proposal_reward
+        .min(ctx.accounts.vault_reward_token_account.amount);
+    let reward_seeds: &[&[u8]] = &[CONFIG_SEED.as_bytes(), &[success.config_account.bump]];
+
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.vault_reward_token_account.to_account_info(),
+        &ctx.accounts.proposer_reward_token_account.to_account_info(),
+        ctx.accounts.reward_mint.clone(),
+        &success.config_account.to_account_info(),
+        &success.token_program.to_account_info(),
+        Some(&success.token_2022_program.to_account_info()),
+        reward_amount,
+        &[&reward_seeds],
+    )?;
+
+    emit!(ProposalSettled {
+        market_key,
+        proposer: ctx.accounts.proposer.key(),
+        answer_key,
+        bond_amount,
+        reward_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::helper::transfer_from_pool_vault_to_user;
+use crate::instructions::admin::sucess_market::*;
+use crate::{
+    error::ProgramErrorCode, DisputeTally, MarketStatus, ProposalStatus, ResolutionProposal,
+    DISPUTE_TALLY_SEED, PROPOSAL_BOND_SEED, RESOLUTION_PROPOSAL_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SettleResolutionVote<'info> {
+    /// `success.owner` is whoever settles the vote and pays for the fee
+    /// accounts.
+    #[account(
+      constraint = success.market_account.status == MarketStatus::Disputed @ ProgramErrorCode::MarketNotDisputed,
+    )]
+    pub success: SuccessMarket<'info>,
+    #[account(
+        mut,
+        seeds = [DISPUTE_TALLY_SEED.as_bytes(), &success.market_account.market_key.to_le_bytes()],
+        bump = dispute_tally.bump,
+    )]
+    pub dispute_tally: Box<Account<'info, DisputeTally>>,
+    /// Required when the market was disputed through a proposal, that is
+    /// whenever `success.resolution_proposal` exists.
+    #[account(
+        seeds = [RESOLUTION_PROPOSAL_SEED.as_bytes(), &success.market_account.market_key.to_le_bytes()],
+        bump = resolution_proposal.bump,
+        constraint = resolution_proposal.status == ProposalStatus::Disputed @ ProgramErrorCode::InvalidProposalStatus,
+    )]
+    pub resolution_proposal: Option<Box<Account<'info, ResolutionProposal>>>,
+    #[account(
+        mut,
+        seeds = [PROPOSAL_BOND_SEED.as_bytes(), &success.market_account.market_key.to_le_bytes()],
+        bump,
+    )]
+    pub bond_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// Token account of the proposal side the vote agrees with.
+    #[account(
+        mut,
+        token::mint = success.bet_mint
+    )]
+    pub winner_bet_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+#[event]
+pub struct ResolutionVoteSettled {
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub winning_weight: u64,
+    pub slashed_bond: u64,
+}
+
+/// Resolve a disputed market to the answer with the most locked weight once
+/// the vote has ended. The proposal side that got it right has its bond
+/// back and the other bond is left for the winning voters.
+pub fn settle_resolution_vote<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettleResolutionVote<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let dispute_tally = &ctx.accounts.dispute_tally;
+
+    require!(
+        (clock.unix_timestamp as u64) >= dispute_tally.vote_end,
+        ProgramErrorCode::VotingNotEnded
+    );
+
+    // a dispute raised through a proposal has to settle its bonds too
+    require!(
+        ctx.accounts.resolution_proposal.is_some()
+            || ctx.accounts.success.resolution_proposal.data_is_empty(),
+        ProgramErrorCode::ProposalPending
+    );
+
+    let leader = dispute_tally.leader().ok_or(ProgramErrorCode::NoVoteLeader)?;
+    let market_key = dispute_tally.market_key;
+
+    resolve_market(
+        &mut ctx.accounts.success,
+        &ctx.bumps.success,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        leader.answer_key,
+    )?;
+
+    let slashed_bond = match &ctx.accounts.resolution_proposal {
+        Some(resolution_proposal) => {
+            let (Some(bond_token_account), Some(winner_bet_token_account)) = (
+                &ctx.accounts.bond_token_account,
+                &ctx.accounts.winner_bet_token_account,
+            ) else {
+                return Err(ProgramErrorCode::InvalidProposalStatus.into());
+            };
+
+            require!(
+                winner_bet_token_account.owner == resolution_proposal.winner(leader.answer_key),
+                ProgramErrorCode::Unauthorized
+            );
+
+            let success = &ctx.accounts.success;
+            let proposal_seeds: &[&[u8]] = &[
+                RESOLUTION_PROPOSAL_SEED.as_bytes(),
+                &market_key.to_le_bytes(),
+                &[resolution_proposal.bump],
+            ];
+
+            transfer_from_pool_vault_to_user(
+                &bond_token_account.to_account_info(),
+                &winner_bet_token_account.to_account_info(),
+                success.bet_mint.clone(),
+                &resolution_proposal.to_account_info(),
+                &success.token_program.to_account_info(),
+                Some(&success.token_2022_program.to_account_info()),
+                resolution_proposal.bond_amount,
+                &[&proposal_seeds],
+            )?;
+
+            resolution_proposal.bond_amount
+        }
+        None => 0,
+    };
+
+    let dispute_tally = &mut ctx.accounts.dispute_tally;
+
+    dispute_tally.settled = true;
+    dispute_tally.winning_answer_key = leader.answer_key;
+    dispute_tally.winning_weight = leader.weight;
+    dispute_tally.slashed_bond = slashed_bond;
+
+    emit!(ResolutionVoteSettled {
+        market_key,
+        answer_key: leader.answer_key,
+        winning_weight: leader.weight,
+        slashed_bond,
+    });
+
+    Ok(())
+}
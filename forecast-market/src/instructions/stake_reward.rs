@@ -0,0 +1,108 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_user_to_pool_vault;
+use crate::{
+    constant::REWARD_STAKE_LOCK_PERIOD, error::ProgramErrorCode, ConfigAccount, RewardStakeAccount,
+    CONFIG_SEED, REWARD_STAKE_SEED, REWARD_STAKE_VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct StakeReward<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        constraint = reward_mint.key() == config_account.reward_mint @ ProgramErrorCode::InvalidRewardMint
+    )]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = owner
+    )]
+    pub user_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      init_if_needed,
+      payer = owner,
+      space = RewardStakeAccount::MAX_SIZE,
+      seeds = [REWARD_STAKE_SEED.as_bytes(), owner.key().as_ref()],
+      bump,
+    )]
+    pub stake_account: Box<Account<'info, RewardStakeAccount>>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [REWARD_STAKE_VAULT_SEED.as_bytes(), owner.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = stake_account
+    )]
+    pub stake_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct RewardStaked {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub unlock_time: u64,
+}
+
+/// Lock reward tokens to qualify for a service fee discount. Every stake
+/// restarts the `REWARD_STAKE_LOCK_PERIOD` lock on the whole balance, so
+/// tokens borrowed for a claim cannot be handed back right after it.
+pub fn stake_reward(ctx: Context<StakeReward>, amount: u64) -> Result<()> {
+    require!(amount > 0, ProgramErrorCode::InvalidStakeAmount);
+
+    let vault_amount = ctx.accounts.stake_vault_token_account.amount;
+    transfer_from_user_to_pool_vault(
+        &ctx.accounts.user_reward_token_account.to_account_info(),
+        &ctx.accounts.stake_vault_token_account.to_account_info(),
+        ctx.accounts.reward_mint.clone(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        amount,
+    )?;
+    ctx.accounts.stake_vault_token_account.reload()?;
+    let received = ctx
+        .accounts
+        .stake_vault_token_account
+        .amount
+        .checked_sub(vault_amount)
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    let clock = Clock::get()?;
+    let stake_account = ctx.accounts.stake_account.deref_mut();
+    stake_account.bump = ctx.bumps.stake_account;
+    stake_account.owner = ctx.accounts.owner.key();
+    stake_account.amount = stake_account
+        .amount
+        .checked_add(received)
+        .ok_or(ProgramErrorCode::Overflow)?;
+    stake_account.unlock_time = (clock.unix_timestamp as u64)
+        .checked_add(REWARD_STAKE_LOCK_PERIOD)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    emit!(RewardStaked {
+        owner: stake_account.owner,
+        amount: received,
+        unlock_time: stake_account.unlock_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_pool_vault_to_user;
+use crate::instructions::sucess_market::credit_revenue_split;
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, FeeLedger, MarketAccount, RevenueSplit, CONFIG_SEED,
+    FEE_LEDGER_SEED, FEE_TREASURY_SEED, MARKET_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SweepServiceFees<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      mut,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [FEE_TREASURY_SEED.as_bytes(), bet_mint.key().as_ref()],
+        bump,
+        token::mint = bet_mint,
+        token::authority = config_account
+    )]
+    pub fee_treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = FeeLedger::MAX_SIZE,
+        seeds = [FEE_LEDGER_SEED.as_bytes(), config_account.service_fee_account.as_ref(), bet_mint.key().as_ref()],
+        bump,
+    )]
+    pub service_fee_ledger: Box<Account<'info, FeeLedger>>,
+    #[account(
+      constraint = service_split.key() == market_account.service_split @ ProgramErrorCode::InvalidRevenueSplit,
+    )]
+    pub service_split: Option<Box<Account<'info, RevenueSplit>>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ServiceFeesSwept {
+    pub market_key: u64,
+    pub amount: u64,
+}
+
+/// Move the service fees charged on a market's claims into the fee treasury
+/// and credit them like `success_market` does. Split recipients' ledgers come
+/// in `remaining_accounts`.
+pub fn sweep_service_fees<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SweepServiceFees<'info>>,
+) -> Result<()> {
+    let amount = ctx.accounts.market_account.service_fee_accrued;
+    require!(amount > 0, ProgramErrorCode::NothingToWithdraw);
+
+    ctx.accounts.market_account.service_fee_accrued = 0;
+
+    let market_account = &ctx.accounts.market_account;
+    let seeds: &[&[u8]] = &[
+        MARKET_SEED.as_bytes(),
+        &market_account.market_key.to_le_bytes(),
+        &[market_account.bump],
+    ];
+
+    let treasury_amount = ctx.accounts.fee_treasury_token_account.amount;
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.vault_token_account.to_account_info(),
+        &ctx.accounts.fee_treasury_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.market_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        amount,
+        &[&seeds],
+    )?;
+
+    // Credit what reached the treasury, net of any transfer fee.
+    ctx.accounts.fee_treasury_token_account.reload()?;
+    let received = ctx
+        .accounts
+        .fee_treasury_token_account
+        .amount
+        .checked_sub(treasury_amount)
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    let bet_mint = ctx.accounts.bet_mint.key();
+    ctx.accounts.service_fee_ledger.init(
+        ctx.bumps.service_fee_ledger,
+        ctx.accounts.config_account.service_fee_account,
+        bet_mint,
+    );
+
+    match &ctx.accounts.service_split {
+        Some(service_split) => credit_revenue_split(
+            service_split,
+            received,
+            &mut ctx.remaining_accounts.iter(),
+            &mut [&mut ctx.accounts.service_fee_ledger],
+            &bet_mint,
+            ctx.program_id,
+        )?,
+        None => {
+            require!(
+                ctx.accounts.market_account.service_split == Pubkey::default(),
+                ProgramErrorCode::InvalidRevenueSplit
+            );
+            ctx.accounts.service_fee_ledger.credit(received)?
+        }
+    }
+
+    emit!(ServiceFeesSwept {
+        market_key: ctx.accounts.market_account.market_key,
+        amount,
+    });
+
+    Ok(())
+}
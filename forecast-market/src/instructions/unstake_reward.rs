@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_pool_vault_to_user;
+use crate::{
+    error::ProgramErrorCode, RewardStakeAccount, REWARD_STAKE_SEED, REWARD_STAKE_VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct UnstakeReward<'info> {
+    pub owner: Signer<'info>,
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = owner
+    )]
+    pub user_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      seeds = [REWARD_STAKE_SEED.as_bytes(), owner.key().as_ref()],
+      bump = stake_account.bump,
+    )]
+    pub stake_account: Box<Account<'info, RewardStakeAccount>>,
+    #[account(
+        mut,
+        seeds = [REWARD_STAKE_VAULT_SEED.as_bytes(), owner.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+    )]
+    pub stake_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[event]
+pub struct RewardUnstaked {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+/// Take staked reward tokens back once the lock has run out.
+pub fn unstake_reward(ctx: Context<UnstakeReward>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let stake_account = &mut ctx.accounts.stake_account;
+    require!(
+        clock.unix_timestamp as u64 >= stake_account.unlock_time,
+        ProgramErrorCode::StakeLocked
+    );
+    stake_account.amount = stake_account
+        .amount
+        .checked_sub(amount)
+        .ok_or(ProgramErrorCode::InsufficientStake)?;
+
+    let owner = ctx.accounts.owner.key();
+    let seeds: &[&[u8]] = &[
+        REWARD_STAKE_SEED.as_bytes(),
+        owner.as_ref(),
+        &[ctx.accounts.stake_account.bump],
+    ];
+
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.stake_vault_token_account.to_account_info(),
+        &ctx.accounts.user_reward_token_account.to_account_info(),
+        ctx.accounts.reward_mint.clone(),
+        &ctx.accounts.stake_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        amount,
+        &[&seeds],
+    )?;
+
+    emit!(RewardUnstaked { owner, amount });
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_pool_vault_to_user;
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, FeeLedger, CONFIG_SEED, FEE_LEDGER_SEED,
+    FEE_TREASURY_SEED,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [FEE_LEDGER_SEED.as_bytes(), recipient.key().as_ref(), bet_mint.key().as_ref()],
+        bump = fee_ledger.bump,
+    )]
+    pub fee_ledger: Box<Account<'info, FeeLedger>>,
+    #[account(
+        mut,
+        seeds = [FEE_TREASURY_SEED.as_bytes(), bet_mint.key().as_ref()],
+        bump,
+        token::mint = bet_mint,
+        token::authority = config_account
+    )]
+    pub fee_treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = recipient
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Pay out everything accrued on the caller's fee ledger.
+pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
+    let fee_ledger = &mut ctx.accounts.fee_ledger;
+    let amount = fee_ledger.pending_tokens();
+    require!(amount > 0, ProgramErrorCode::NothingToWithdraw);
+
+    fee_ledger.withdrawn_tokens = fee_ledger.accrued_tokens;
+
+    let seeds: &[&[u8]] = &[CONFIG_SEED.as_bytes(), &[ctx.accounts.config_account.bump]];
+
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.fee_treasury_token_account.to_account_info(),
+        &ctx.accounts.recipient_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.config_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        amount,
+        &[&seeds],
+    )?;
+
+    emit!(FeesWithdrawn {
+        recipient: ctx.accounts.recipient.key(),
+        mint: ctx.accounts.bet_mint.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_pool_vault_to_user;
+use crate::{IntentNonceAccount, INTENT_ESCROW_SEED, INTENT_NONCE_SEED};
+
+#[derive(Accounts)]
+pub struct WithdrawIntentEscrow<'info> {
+    pub voter: Signer<'info>,
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = voter
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      seeds = [INTENT_NONCE_SEED.as_bytes(), voter.key().as_ref()],
+      bump = nonce_account.bump,
+    )]
+    pub nonce_account: Box<Account<'info, IntentNonceAccount>>,
+    #[account(
+        mut,
+        seeds = [INTENT_ESCROW_SEED.as_bytes(), voter.key().as_ref(), bet_mint.key().as_ref()],
+        bump,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[event]
+pub struct IntentEscrowWithdrawn {
+    pub voter: Pubkey,
+    pub bet_mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Take tokens back out of the voter's intent escrow. Intents signed for
+/// more than is left simply fail.
+pub fn withdraw_intent_escrow(ctx: Context<WithdrawIntentEscrow>, amount: u64) -> Result<()> {
+    let voter = ctx.accounts.voter.key();
+    let seeds: &[&[u8]] = &[
+        INTENT_NONCE_SEED.as_bytes(),
+        voter.as_ref(),
+        &[ctx.accounts.nonce_account.bump],
+    ];
+
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.bet_mint.clone(),
+        &ctx.accounts.nonce_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        amount,
+        &[&seeds],
+    )?;
+
+    emit!(IntentEscrowWithdrawn {
+        voter,
+        bet_mint: ctx.accounts.bet_mint.key(),
+        amount,
+    });
+
+    Ok(())
+}
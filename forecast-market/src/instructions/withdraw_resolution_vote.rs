@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::helper::transfer_from_pool_vault_to_user;
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, DisputeTally, MarketAccount, MarketStatus,
+    ResolutionProposal, ResolutionVote, CONFIG_SEED, DISPUTE_TALLY_SEED, PROPOSAL_BOND_SEED,
+    RESOLUTION_PROPOSAL_SEED, RESOLUTION_VOTE_SEED, VOTE_LOCK_SEED,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawResolutionVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, ConfigAccount>>,
+    #[account(
+      constraint = market_account.status != MarketStatus::Disputed @ ProgramErrorCode::VotingNotEnded,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+        address = config_account.reward_mint @ ProgramErrorCode::InvalidRewardMint
+    )]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = voter
+    )]
+    pub voter_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [DISPUTE_TALLY_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump = dispute_tally.bump,
+    )]
+    pub dispute_tally: Box<Account<'info, DisputeTally>>,
+    #[account(
+        mut,
+        seeds = [VOTE_LOCK_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump,
+    )]
+    pub vote_lock_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        close = voter,
+        seeds = [RESOLUTION_VOTE_SEED.as_bytes(), &market_account.market_key.to_le_bytes(), voter.key().as_ref()],
+        bump = resolution_vote.bump,
+    )]
+    pub resolution_vote: Box<Account<'info, ResolutionVote>>,
+    /// Bond share accounts, required when the vote won a share of a slashed bond.
+    #[account(
+        address = market_account.bet_mint @ ProgramErrorCode::InvalidBetMint
+    )]
+    pub bet_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(
+        mut,
+        token::mint = market_account.bet_mint,
+        token::authority = voter
+    )]
+    pub voter_bet_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        seeds = [RESOLUTION_PROPOSAL_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump = resolution_proposal.bump,
+    )]
+    pub resolution_proposal: Option<Box<Account<'info, ResolutionProposal>>>,
+    #[account(
+        mut,
+        seeds = [PROPOSAL_BOND_SEED.as_bytes(), &market_account.market_key.to_le_bytes()],
+        bump,
+    )]
+    pub bond_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[event]
+pub struct ResolutionVoteWithdrawn {
+    pub market_key: u64,
+    pub voter: Pubkey,
+    pub amount: u64,
+    pub bond_share: u64,
+}
+
+/// Unlock a voter's reward tokens once the disputed market is resolved.
+/// Voters of the winning answer also take their share of the slashed bond.
+pub fn withdraw_resolution_vote(ctx: Context<WithdrawResolutionVote>) -> Result<()> {
+    let market_key = ctx.accounts.market_account.market_key;
+    let dispute_tally = &ctx.accounts.dispute_tally;
+    let resolution_vote = &ctx.accounts.resolution_vote;
+
+    let tally_seeds: &[&[u8]] = &[
+        DISPUTE_TALLY_SEED.as_bytes(),
+        &market_key.to_le_bytes(),
+        &[dispute_tally.bump],
+    ];
+
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.vote_lock_token_account.to_account_info(),
+        &ctx.accounts.voter_reward_token_account.to_account_info(),
+        ctx.accounts.reward_mint.clone(),
+        &dispute_tally.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(&ctx.accounts.token_2022_program.to_account_info()),
+        resolution_vote.amount,
+        &[&tally_seeds],
+    )?;
+
+    let bond_share = if dispute_tally.settled
+        && resolution_vote.answer_key == dispute_tally.winning_answer_key
+    {
+        dispute_tally.bond_share(resolution_vote.amount)?
+    } else {
+        0
+    };
+
+    if bond_share > 0 {
+        let (
+            Some(bet_mint),
+            Some(voter_bet_token_account),
+            Some(resolution_proposal),
+            Some(bond_token_account),
+        ) = (
+            &ctx.accounts.bet_mint,
+            &ctx.accounts.voter_bet_token_account,
+            &ctx.accounts.resolution_proposal,
+            &ctx.accounts.bond_token_account,
+        )
+        else {
+            return Err(ProgramErrorCode::InvalidProposalStatus.into());
+        };
+
+        let proposal_seeds: &[&[u8]] = &[
+            RESOLUTION_PROPOSAL_SEED.as_bytes(),
+            &market_key.to_le_bytes(),
+            &[resolution_proposal.bump],
+        ];
+
+        transfer_from_pool_vault_to_user(
+            &bond_token_account.to_account_info(),
+            &voter_bet_token_account.to_account_info(),
+            bet_mint.clone(),
+            &resolution_proposal.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&ctx.accounts.token_2022_program.to_account_info()),
+            bond_share,
+            &[&proposal_seeds],
+        )?;
+    }
+
+    emit!(ResolutionVoteWithdrawn {
+        market_key,
+        voter: ctx.accounts.voter.key(),
+        amount: resolution_vote.amount,
+        bond_share,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use crate::helper::{transfer_from_pool_vault_to_user, transfer_market_reward};
+use crate::{
+    error::ProgramErrorCode, ConfigAccount, MarketAccount, VestingAccount, CONFIG_SEED,
+    MARKET_SEED,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawVested<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(mut)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        address = config_account.reward_mint @ ProgramErrorCode::InvalidRewardMint
+    )]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = owner
+    )]
+    pub user_bet_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = market_account
+    )]
+    pub vault_bet_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = reward_mint,
+        associated_token::authority = owner
+    )]
+    pub user_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// The market's reward escrow, see `transfer_market_reward`.
+    #[account(
+        mut,
+        token::mint = reward_mint,
+    )]
+    pub vault_reward_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      mut,
+      constraint = market_account.bet_mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+        mut,
+        has_one = owner @ ProgramErrorCode::Unauthorized,
+        constraint = vesting_account.market_key == market_account.market_key @ ProgramErrorCode::Unauthorized,
+    )]
+    pub vesting_account: Box<Account<'info, VestingAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct VestedWithdrawn {
+    pub owner: Pubkey,
+    pub market_key: u64,
+    pub bet_amount: u64,
+    pub reward_amount: u64,
+}
+
+/// Release whatever has vested so far, and close the vesting account once
+/// everything has been withdrawn.
+pub fn withdraw_vested(ctx: Context<WithdrawVested>) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    let vesting_account = &mut ctx.accounts.vesting_account;
+    let bet_amount = vesting_account.withdrawable_bet_amount(now);
+    let reward_amount = vesting_account.withdrawable_reward_amount(now);
+    require!(
+        bet_amount > 0 || reward_amount > 0,
+        ProgramErrorCode::NothingToWithdraw
+    );
+
+    vesting_account.bet_withdrawn += bet_amount;
+    vesting_account.reward_withdrawn += reward_amount;
+
+    // Saturating: claims vested before markets tracked them are not counted.
+    let market_account = &mut ctx.accounts.market_account;
+    market_account.vesting_bet_tokens = market_account
+        .vesting_bet_tokens
+        .saturating_sub(bet_amount);
+    market_account.vesting_reward_tokens = market_account
+        .vesting_reward_tokens
+        .saturating_sub(reward_amount);
+
+    if bet_amount > 0 {
+        let market_account = &ctx.accounts.market_account;
+        let bet_seeds: &[&[u8]] = &[
+            MARKET_SEED.as_bytes(),
+            &market_account.market_key.to_le_bytes(),
+            &[market_account.bump],
+        ];
+
+        transfer_from_pool_vault_to_user(
+            &ctx.accounts.vault_bet_token_account.to_account_info(),
+            &ctx.accounts.user_bet_token_account.to_account_info(),
+            ctx.accounts.bet_mint.clone(),
+            &ctx.accounts.market_account.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&ctx.accounts.token_2022_program.to_account_info()),
+            bet_amount,
+            &[&bet_seeds],
+        )?;
+    }
+
+    if reward_amount > 0 {
+        transfer_market_reward(
+            &ctx.accounts.vault_reward_token_account,
+            &ctx.accounts.user_reward_token_account.to_account_info(),
+            ctx.accounts.reward_mint.clone(),
+            &ctx.accounts.market_account,
+            &ctx.accounts.config_account,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.token_2022_program.to_account_info(),
+            reward_amount,
+        )?;
+    }
+
+    emit!(VestedWithdrawn {
+        owner: ctx.accounts.owner.key(),
+        market_key: ctx.accounts.market_account.market_key,
+        bet_amount,
+        reward_amount,
+    });
+
+    if ctx.accounts.vesting_account.is_fully_withdrawn() {
+        ctx.accounts
+            .vesting_account
+            .close(ctx.accounts.owner.to_account_info())?;
+    }
+
+    Ok(())
+}
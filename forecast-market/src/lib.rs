@@ -27,26 +27,36 @@ pub mod forecast_market {
         Ok(())
     }
 
-    pub fn update_owner(ctx: Context<UpdateOwner>, new_owner: Pubkey) -> Result<()> {
-        instructions::update_owner(ctx, new_owner)?;
+    pub fn propose_owner(ctx: Context<ProposeOwner>, new_owner: Pubkey, delay: u64) -> Result<()> {
+        instructions::propose_owner(ctx, new_owner, delay)?;
         Ok(())
     }
 
-    pub fn set_account(
-        ctx: Context<SetAccount>,
-        service_fee_account: Option<Pubkey>,
-        remain_account: Option<Pubkey>,
-    ) -> Result<()> {
-        instructions::set_account(ctx, service_fee_account, remain_account)?;
+    pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+        instructions::accept_owner(ctx)?;
+        Ok(())
+    }
+
+    pub fn cancel_owner_transfer(ctx: Context<CancelOwnerTransfer>) -> Result<()> {
+        instructions::cancel_owner_transfer(ctx)?;
         Ok(())
     }
 
-    pub fn update_reward_config(
-        ctx: Context<UpdateRewardConfig>,
-        reward_mint: Option<Pubkey>,
-        reward_apr: Option<u64>,
+    pub fn schedule_config_change(
+        ctx: Context<ScheduleConfigChange>,
+        change: ConfigChange,
     ) -> Result<()> {
-        instructions::update_reward_config(ctx, reward_mint, reward_apr)?;
+        instructions::schedule_config_change(ctx, change)?;
+        Ok(())
+    }
+
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+        instructions::execute_config_change(ctx)?;
+        Ok(())
+    }
+
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        instructions::cancel_config_change(ctx)?;
         Ok(())
     }
 
@@ -56,8 +66,8 @@ pub mod forecast_market {
         creator: Pubkey,
         title: String,
         create_fee: u64,
-        creator_fee_percentage: u64,
-        cojam_fee_percentage: u64,
+        creator_fee_percentage: Option<u64>,
+        cojam_fee_percentage: Option<u64>,
     ) -> Result<()> {
         instructions::draft_market(
             ctx,
@@ -71,8 +81,12 @@ pub mod forecast_market {
         Ok(())
     }
 
-    pub fn approve_market(ctx: Context<ApproveMarket>) -> Result<()> {
-        instructions::approve_market(ctx)?;
+    pub fn approve_market(
+        ctx: Context<ApproveMarket>,
+        reward_budget: u64,
+        reward_policy: RewardPolicy,
+    ) -> Result<()> {
+        instructions::approve_market(ctx, reward_budget, reward_policy)?;
         Ok(())
     }
 
@@ -86,7 +100,10 @@ pub mod forecast_market {
         Ok(())
     }
 
-    pub fn success_market(ctx: Context<SuccessMarket>, answer_key: u64) -> Result<()> {
+    pub fn success_market<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SuccessMarket<'info>>,
+        answer_key: u64,
+    ) -> Result<()> {
         instructions::success_market(ctx, answer_key)?;
         Ok(())
     }
@@ -114,4 +131,361 @@ pub mod forecast_market {
         instructions::claim_token(ctx)?;
         Ok(())
     }
+
+    pub fn quote_bet(ctx: Context<QuoteBet>, answer_key: u64, amount: u64) -> Result<()> {
+        instructions::quote_bet(ctx, answer_key, amount)?;
+        Ok(())
+    }
+
+    pub fn quote_claim(ctx: Context<QuoteClaim>) -> Result<()> {
+        instructions::quote_claim(ctx)?;
+        Ok(())
+    }
+
+    pub fn fund_lmsr_market(ctx: Context<FundLmsrMarket>, liquidity_param: u64) -> Result<()> {
+        instructions::fund_lmsr_market(ctx, liquidity_param)?;
+        Ok(())
+    }
+
+    pub fn buy_shares(
+        ctx: Context<BuyShares>,
+        answer_key: u64,
+        shares: u64,
+        max_cost: u64,
+    ) -> Result<()> {
+        instructions::buy_shares(ctx, answer_key, shares, max_cost)?;
+        Ok(())
+    }
+
+    pub fn sell_shares(
+        ctx: Context<SellShares>,
+        answer_key: u64,
+        shares: u64,
+        min_proceeds: u64,
+    ) -> Result<()> {
+        instructions::sell_shares(ctx, answer_key, shares, min_proceeds)?;
+        Ok(())
+    }
+
+    pub fn claim_shares(ctx: Context<ClaimShares>) -> Result<()> {
+        instructions::claim_shares(ctx)?;
+        Ok(())
+    }
+
+    pub fn create_outcome_mint(ctx: Context<CreateOutcomeMint>, answer_key: u64) -> Result<()> {
+        instructions::create_outcome_mint(ctx, answer_key)?;
+        Ok(())
+    }
+
+    pub fn mint_complete_set<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintCompleteSet<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::mint_complete_set(ctx, amount)?;
+        Ok(())
+    }
+
+    pub fn merge_complete_set<'info>(
+        ctx: Context<'_, '_, '_, 'info, MergeCompleteSet<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::merge_complete_set(ctx, amount)?;
+        Ok(())
+    }
+
+    pub fn redeem_outcome_tokens<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemOutcomeTokens<'info>>,
+        answer_key: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::redeem_outcome_tokens(ctx, answer_key, amount)?;
+        Ok(())
+    }
+
+    pub fn set_answer_odds(ctx: Context<SetAnswerOdds>, answer_key: u64, odds: u64) -> Result<()> {
+        instructions::set_answer_odds(ctx, answer_key, odds)?;
+        Ok(())
+    }
+
+    pub fn fund_house_vault(ctx: Context<FundHouseVault>, amount: u64) -> Result<()> {
+        instructions::fund_house_vault(ctx, amount)?;
+        Ok(())
+    }
+
+    pub fn withdraw_house_tokens(ctx: Context<WithdrawHouseTokens>) -> Result<()> {
+        instructions::withdraw_house_tokens(ctx)?;
+        Ok(())
+    }
+
+    pub fn bet_with_intent(
+        ctx: Context<BetWithIntent>,
+        answer_key: u64,
+        amount: u64,
+        nonce: u64,
+        expiry: u64,
+    ) -> Result<()> {
+        instructions::bet_with_intent(ctx, answer_key, amount, nonce, expiry)?;
+        Ok(())
+    }
+
+    pub fn fund_intent_escrow(ctx: Context<FundIntentEscrow>, amount: u64) -> Result<()> {
+        instructions::fund_intent_escrow(ctx, amount)?;
+        Ok(())
+    }
+
+    pub fn withdraw_intent_escrow(ctx: Context<WithdrawIntentEscrow>, amount: u64) -> Result<()> {
+        instructions::withdraw_intent_escrow(ctx, amount)?;
+        Ok(())
+    }
+
+    pub fn stake_reward(ctx: Context<StakeReward>, amount: u64) -> Result<()> {
+        instructions::stake_reward(ctx, amount)?;
+        Ok(())
+    }
+
+    pub fn unstake_reward(ctx: Context<UnstakeReward>, amount: u64) -> Result<()> {
+        instructions::unstake_reward(ctx, amount)?;
+        Ok(())
+    }
+
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        session_key: Pubkey,
+        spend_limit: u64,
+        expiry: u64,
+        allowed_markets: Vec<u64>,
+    ) -> Result<()> {
+        instructions::create_session(ctx, session_key, spend_limit, expiry, allowed_markets)?;
+        Ok(())
+    }
+
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        instructions::revoke_session(ctx)?;
+        Ok(())
+    }
+
+    pub fn claim_many<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimMany<'info>>) -> Result<()> {
+        instructions::claim_many(ctx)?;
+        Ok(())
+    }
+
+    pub fn crank_claim(ctx: Context<CrankClaim>) -> Result<()> {
+        instructions::crank_claim(ctx)?;
+        Ok(())
+    }
+
+    pub fn claim_cross_chain(ctx: Context<ClaimCrossChain>) -> Result<()> {
+        instructions::claim_cross_chain(ctx)?;
+        Ok(())
+    }
+
+    pub fn set_market_vesting(
+        ctx: Context<SetMarketVesting>,
+        vesting_threshold: u64,
+        vesting_period: u64,
+    ) -> Result<()> {
+        instructions::set_market_vesting(ctx, vesting_threshold, vesting_period)?;
+        Ok(())
+    }
+
+    pub fn withdraw_vested(ctx: Context<WithdrawVested>) -> Result<()> {
+        instructions::withdraw_vested(ctx)?;
+        Ok(())
+    }
+
+    pub fn set_fee_schedule(
+        ctx: Context<SetFeeSchedule>,
+        default_creator_fee_percentage: u64,
+        default_service_fee_percentage: u64,
+        max_total_fee_percentage: u64,
+    ) -> Result<()> {
+        instructions::set_fee_schedule(
+            ctx,
+            default_creator_fee_percentage,
+            default_service_fee_percentage,
+            max_total_fee_percentage,
+        )?;
+        Ok(())
+    }
+
+    pub fn set_creator_fee_override(
+        ctx: Context<SetCreatorFeeOverride>,
+        creator: Pubkey,
+        fees: Option<FeePercentages>,
+    ) -> Result<()> {
+        instructions::set_creator_fee_override(ctx, creator, fees)?;
+        Ok(())
+    }
+
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
+        instructions::withdraw_fees(ctx)?;
+        Ok(())
+    }
+
+    pub fn set_revenue_split(
+        ctx: Context<SetRevenueSplit>,
+        split_key: u64,
+        recipients: Vec<SplitRecipient>,
+    ) -> Result<()> {
+        instructions::set_revenue_split(ctx, split_key, recipients)?;
+        Ok(())
+    }
+
+    pub fn set_market_revenue_splits(ctx: Context<SetMarketRevenueSplits>) -> Result<()> {
+        instructions::set_market_revenue_splits(ctx)?;
+        Ok(())
+    }
+
+    pub fn open_fee_ledger(ctx: Context<OpenFeeLedger>, recipient: Pubkey) -> Result<()> {
+        instructions::open_fee_ledger(ctx, recipient)?;
+        Ok(())
+    }
+
+    pub fn set_fee_discount_tiers(
+        ctx: Context<SetFeeDiscountTiers>,
+        tiers: Vec<FeeDiscountTier>,
+    ) -> Result<()> {
+        instructions::set_fee_discount_tiers(ctx, tiers)?;
+        Ok(())
+    }
+
+    pub fn sweep_service_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SweepServiceFees<'info>>,
+    ) -> Result<()> {
+        instructions::sweep_service_fees(ctx)?;
+        Ok(())
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, key: Pubkey, role: Role) -> Result<()> {
+        instructions::grant_role(ctx, key, role)?;
+        Ok(())
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, key: Pubkey, role: Role) -> Result<()> {
+        instructions::revoke_role(ctx, key, role)?;
+        Ok(())
+    }
+
+    pub fn set_resolver_committee(
+        ctx: Context<SetResolverCommittee>,
+        committee_key: u64,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_resolver_committee(ctx, committee_key, members, threshold)?;
+        Ok(())
+    }
+
+    pub fn set_market_committee(ctx: Context<SetMarketCommittee>) -> Result<()> {
+        instructions::set_market_committee(ctx)?;
+        Ok(())
+    }
+
+    pub fn attest_resolution(ctx: Context<AttestResolution>, answer_key: u64) -> Result<()> {
+        instructions::attest_resolution(ctx, answer_key)?;
+        Ok(())
+    }
+
+    pub fn set_oracle_config(
+        ctx: Context<SetOracleConfig>,
+        arbiter: Option<Pubkey>,
+        bond_basis_points: u16,
+        liveness: u64,
+        proposal_reward: u64,
+        vote_period: u64,
+    ) -> Result<()> {
+        instructions::set_oracle_config(
+            ctx,
+            arbiter,
+            bond_basis_points,
+            liveness,
+            proposal_reward,
+            vote_period,
+        )?;
+        Ok(())
+    }
+
+    pub fn propose_resolution(ctx: Context<ProposeResolution>, answer_key: u64) -> Result<()> {
+        instructions::propose_resolution(ctx, answer_key)?;
+        Ok(())
+    }
+
+    pub fn dispute_proposal(ctx: Context<DisputeProposal>) -> Result<()> {
+        instructions::dispute_proposal(ctx)?;
+        Ok(())
+    }
+
+    pub fn settle_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleProposal<'info>>,
+    ) -> Result<()> {
+        instructions::settle_proposal(ctx)?;
+        Ok(())
+    }
+
+    pub fn rule_dispute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RuleDispute<'info>>,
+        correct_answer_key: u64,
+    ) -> Result<()> {
+        instructions::rule_dispute(ctx, correct_answer_key)?;
+        Ok(())
+    }
+
+    pub fn open_resolution_vote(ctx: Context<OpenResolutionVote>) -> Result<()> {
+        instructions::open_resolution_vote(ctx)?;
+        Ok(())
+    }
+
+    pub fn cast_resolution_vote(
+        ctx: Context<CastResolutionVote>,
+        answer_key: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::cast_resolution_vote(ctx, answer_key, amount)?;
+        Ok(())
+    }
+
+    pub fn settle_resolution_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleResolutionVote<'info>>,
+    ) -> Result<()> {
+        instructions::settle_resolution_vote(ctx)?;
+        Ok(())
+    }
+
+    pub fn withdraw_resolution_vote(ctx: Context<WithdrawResolutionVote>) -> Result<()> {
+        instructions::withdraw_resolution_vote(ctx)?;
+        Ok(())
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate_config(ctx)?;
+        Ok(())
+    }
+
+    pub fn migrate_market(ctx: Context<MigrateMarket>) -> Result<()> {
+        instructions::migrate_market(ctx)?;
+        Ok(())
+    }
+
+    pub fn migrate_answer(ctx: Context<MigrateAnswer>) -> Result<()> {
+        instructions::migrate_answer(ctx)?;
+        Ok(())
+    }
+
+    pub fn migrate_betting(ctx: Context<MigrateBetting>) -> Result<()> {
+        instructions::migrate_betting(ctx)?;
+        Ok(())
+    }
+
+    pub fn migrate_betting_cross_chain(ctx: Context<MigrateBettingCrossChain>) -> Result<()> {
+        instructions::migrate_betting_cross_chain(ctx)?;
+        Ok(())
+    }
+
+    pub fn set_market_operator(
+        ctx: Context<SetMarketOperator>,
+        operator: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_market_operator(ctx, operator)?;
+        Ok(())
+    }
 }
@@ -5,12 +5,22 @@ use wormhole_io::Readable;
 
 const PAYLOAD_ID_ALIVE: u8 = 0;
 const PAYLOAD_ID_MESSAGE: u8 = 1;
+const PAYLOAD_ID_PAYOUT: u8 = 2;
 pub const MESSAGE_MAX_LENGTH: usize = 2048;
 
 #[derive(Clone)]
 pub enum PredixMessage {
     Alive { program_id: Pubkey },
     Message { message: Vec<u8> },
+    /// Settlement of a cross-chain bet, released by the contract on `chain_id`.
+    Payout {
+        market_key: u64,
+        answer_key: u64,
+        chain_id: u16,
+        voter_wallet_address: [u8; 32],
+        token_address: [u8; 32],
+        amount: u64,
+    },
 }
 
 impl AnchorSerialize for PredixMessage {
@@ -28,6 +38,22 @@ impl AnchorSerialize for PredixMessage {
                 }
                 Ok(())
             }
+            PredixMessage::Payout {
+                market_key,
+                answer_key,
+                chain_id,
+                voter_wallet_address,
+                token_address,
+                amount,
+            } => {
+                PAYLOAD_ID_PAYOUT.serialize(writer)?;
+                market_key.to_be_bytes().serialize(writer)?;
+                answer_key.to_be_bytes().serialize(writer)?;
+                chain_id.to_be_bytes().serialize(writer)?;
+                voter_wallet_address.serialize(writer)?;
+                token_address.serialize(writer)?;
+                amount.to_be_bytes().serialize(writer)
+            }
         }
     }
 }
@@ -44,6 +70,14 @@ impl AnchorDeserialize for PredixMessage {
                 reader.read_exact(&mut buf)?;
                 Ok(PredixMessage::Message { message: buf })
             }
+            PAYLOAD_ID_PAYOUT => Ok(PredixMessage::Payout {
+                market_key: u64::read(reader)?,
+                answer_key: u64::read(reader)?,
+                chain_id: u16::read(reader)?,
+                voter_wallet_address: <[u8; 32]>::read(reader)?,
+                token_address: <[u8; 32]>::read(reader)?,
+                amount: u64::read(reader)?,
+            }),
             _ => Err(io::Error::new(
                 io::ErrorKind::InvalidInput,
                 "invalid payload ID",
@@ -10,13 +10,36 @@ pub struct Answer {
     pub answer_total_tokens: u64,
 }
 
+/// Decimal odds of an answer in a fixed-odds market, scaled by
+/// `ODDS_BASIS_POINTS`, and the total payout owed if the answer wins.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AnswerOdds {
+    pub answer_key: u64,
+    pub odds: u64,
+    pub liability: u64,
+}
+
 #[account]
+#[derive(Default)]
 pub struct AnswerAccount {
+    pub version: u8,
     pub bump: u8,
     pub answers: Vec<Answer>,
     pub exist: bool,
+    pub odds: Vec<AnswerOdds>,
 }
 
 impl AnswerAccount {
-    pub const MAX_SIZE: usize = 8 + 1 + 4 + (8 + 8) * MAX_ANWSER + 1;
+    pub const VERSION: u8 = 1;
+
+    pub const MAX_SIZE: usize =
+        8 + 1 + 1 + 4 + (8 + 8) * MAX_ANWSER + 1 + 4 + (8 + 8 + 8) * MAX_ANWSER;
+
+    pub fn max_liability(&self) -> u64 {
+        self.odds
+            .iter()
+            .map(|answer_odds| answer_odds.liability)
+            .max()
+            .unwrap_or(0)
+    }
 }
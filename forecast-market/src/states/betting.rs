@@ -2,7 +2,9 @@ use anchor_lang::prelude::*;
 pub const BETTING_SEED: &str = "betting";
 
 #[account]
+#[derive(Default)]
 pub struct BettingAccount {
+    pub version: u8,
     pub bump: u8, //bump for identify
     pub market_key: u64,
     pub answer_key: u64,
@@ -10,15 +12,20 @@ pub struct BettingAccount {
     pub tokens: u64,
     pub create_time: u64,
     pub exist: bool,
+    pub locked_payout: u64,
 }
 
 impl BettingAccount {
+    pub const VERSION: u8 = 1;
+
     pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // version
         1 + // bump
         8 + // market_key
         8 + // answer_key
         32 + // voter (Pubkey)
         8 + // tokens
         8 + // create_time (i64)
-        1; // exist (bool)
+        1 + // exist (bool)
+        8; // locked_payout
 }
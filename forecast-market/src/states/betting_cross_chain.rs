@@ -3,11 +3,13 @@ use serde_json::Value;
 use std::str::FromStr;
 
 pub const BETTING_CROSS_CHAIN_SEED: &str = "betting_cross_chain";
+pub const CROSS_CHAIN_PAYOUT_SEED: &str = "cross_chain_payout";
 
 #[account]
 #[derive(Debug)]
 
 pub struct BettingCrossChainAccount {
+    pub version: u8,
     pub bump: u8, //bump for identify
     pub market_key: u64,
     pub answer_key: u64,
@@ -17,10 +19,14 @@ pub struct BettingCrossChainAccount {
     pub voter_wallet_address: [u8; 32],
     pub token_address: [u8; 32],
     pub tokens: u64,
+    pub settled: bool,
 }
 
 impl BettingCrossChainAccount {
+    pub const VERSION: u8 = 1;
+
     pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // version
         1 + // bump
         8 + // market_key
         8 + // answer_key
@@ -29,7 +35,8 @@ impl BettingCrossChainAccount {
         2 + // chainid (Chain)
         32 + // voter_wallet_address
         32 + // token_address
-        8; // tokens
+        8 + // tokens
+        1; // settled
 }
 #[derive(Debug)]
 pub struct BettingCrossChainData {
@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 pub const CONFIG_SEED: &str = "config";
 #[account]
 pub struct ConfigAccount {
+    pub version: u8,
     pub bump: u8,
     pub is_initialized: bool,
     pub owner: Pubkey,
@@ -13,7 +14,10 @@ pub struct ConfigAccount {
 }
 
 impl ConfigAccount {
+    pub const VERSION: u8 = 1;
+
     pub const LEN: usize = 8 // Account discriminator added by Anchor for each account
+            + 1 // version
             + 1 // bump
             + 1 //is_initialized
             + 32 //owner
@@ -22,3 +26,14 @@ impl ConfigAccount {
             + 32 //service_fee_account
             + 32; //remain_account
 }
+
+/// Config setting named in `ConfigKeyChanged` and `ConfigValueChanged`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConfigField {
+    Owner,
+    RewardMint,
+    RewardApr,
+    ServiceFeeAccount,
+    RemainAccount,
+    ConfigDelay,
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::{constant::MIN_CONFIG_CHANGE_DELAY, ConfigAccount, Role};
+
+pub const CONFIG_TIMELOCK_SEED: &str = "config_timelock";
+pub const PENDING_CONFIG_CHANGE_SEED: &str = "pending_config_change";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConfigChange {
+    SetAccount {
+        service_fee_account: Option<Pubkey>,
+        remain_account: Option<Pubkey>,
+    },
+    UpdateRewardConfig {
+        reward_mint: Option<Pubkey>,
+        reward_apr: Option<u64>,
+    },
+    ConfigDelay {
+        delay: u64,
+    },
+}
+
+impl ConfigChange {
+    pub const MAX_SIZE: usize = 1 + // variant
+        (1 + 32) + // service_fee_account / reward_mint - Option<Pubkey>
+        (1 + 32); // remain_account - Option<Pubkey>, larger than the other payloads
+
+    /// Role allowed to schedule the change.
+    pub fn role(&self) -> Role {
+        match self {
+            ConfigChange::SetAccount { .. } => Role::Treasurer,
+            ConfigChange::UpdateRewardConfig { .. } | ConfigChange::ConfigDelay { .. } => {
+                Role::Admin
+            }
+        }
+    }
+
+    pub fn apply(&self, config_account: &mut ConfigAccount, timelock: &mut ConfigTimelockAccount) {
+        match *self {
+            ConfigChange::SetAccount {
+                service_fee_account,
+                remain_account,
+            } => {
+                if let Some(service_fee_account) = service_fee_account {
+                    config_account.service_fee_account = service_fee_account;
+                }
+                if let Some(remain_account) = remain_account {
+                    config_account.remain_account = remain_account;
+                }
+            }
+            ConfigChange::UpdateRewardConfig {
+                reward_mint,
+                reward_apr,
+            } => {
+                if let Some(reward_mint) = reward_mint {
+                    config_account.reward_mint = reward_mint;
+                }
+                if let Some(reward_apr) = reward_apr {
+                    config_account.reward_apr = reward_apr;
+                }
+            }
+            ConfigChange::ConfigDelay { delay } => timelock.delay = delay,
+        }
+    }
+}
+
+/// Delay every `ConfigChange` waits before it can be executed.
+#[account]
+pub struct ConfigTimelockAccount {
+    pub bump: u8,
+    pub delay: u64,
+    pub next_change_id: u64,
+}
+
+impl ConfigTimelockAccount {
+    pub const LEN: usize = 8 + // discriminator
+        1 + // bump
+        8 + // delay
+        8; // next_change_id
+
+    /// Set up the timelock the first time it is used. A delay of zero is
+    /// never valid, so it marks a timelock created without one.
+    pub fn init(&mut self, bump: u8) {
+        self.bump = bump;
+        if self.delay == 0 {
+            self.delay = MIN_CONFIG_CHANGE_DELAY;
+        }
+    }
+}
+
+#[account]
+pub struct PendingConfigChange {
+    pub bump: u8,
+    pub change_id: u64,
+    pub proposer: Pubkey,
+    pub change: ConfigChange,
+    pub executable_time: u64,
+}
+
+impl PendingConfigChange {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        8 + // change_id
+        32 + // proposer (Pubkey)
+        ConfigChange::MAX_SIZE + // change
+        8; // executable_time
+}
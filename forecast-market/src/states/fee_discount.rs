@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{constant::BASIS_POINTS, error::ProgramErrorCode};
+
+pub const FEE_DISCOUNT_SEED: &str = "fee_discount";
+
+pub const MAX_FEE_DISCOUNT_TIERS: usize = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct FeeDiscountTier {
+    /// Reward tokens the claimer must have staked to get the discount.
+    pub min_balance: u64,
+    /// Discount on the service fee, in basis points of the fee.
+    pub discount_bps: u16,
+}
+
+impl FeeDiscountTier {
+    pub const LEN: usize = 8 + // min_balance
+        2; // discount_bps
+}
+
+/// Service fee discounts for stakers of the reward token.
+#[account]
+pub struct FeeDiscountTiers {
+    pub bump: u8,
+    pub tiers: Vec<FeeDiscountTier>,
+}
+
+impl FeeDiscountTiers {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        4 + MAX_FEE_DISCOUNT_TIERS * FeeDiscountTier::LEN; // tiers
+
+    pub fn validate(tiers: &[FeeDiscountTier]) -> Result<()> {
+        require!(
+            tiers.len() <= MAX_FEE_DISCOUNT_TIERS
+                && tiers
+                    .iter()
+                    .all(|tier| tier.discount_bps as u64 <= BASIS_POINTS as u64),
+            ProgramErrorCode::InvalidFeeDiscount
+        );
+        Ok(())
+    }
+
+    /// Best discount a staker of `balance` reward tokens qualifies for.
+    pub fn discount_for(&self, balance: u64) -> u16 {
+        self.tiers
+            .iter()
+            .filter(|tier| balance >= tier.min_balance)
+            .map(|tier| tier.discount_bps)
+            .max()
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ProgramErrorCode;
+
+pub const FEE_LEDGER_SEED: &str = "fee_ledger";
+pub const FEE_TREASURY_SEED: &str = "fee_treasury";
+
+/// Fees owed to `recipient` in `mint`. The tokens sit in the fee treasury
+/// token account of that mint until `withdraw_fees`.
+#[account]
+pub struct FeeLedger {
+    pub bump: u8,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub accrued_tokens: u64,
+    pub withdrawn_tokens: u64,
+}
+
+impl FeeLedger {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        32 + // recipient (Pubkey)
+        32 + // mint (Pubkey)
+        8 + // accrued_tokens
+        8; // withdrawn_tokens
+
+    pub fn init(&mut self, bump: u8, recipient: Pubkey, mint: Pubkey) {
+        self.bump = bump;
+        self.recipient = recipient;
+        self.mint = mint;
+    }
+
+    pub fn credit(&mut self, amount: u64) -> Result<()> {
+        self.accrued_tokens = self
+            .accrued_tokens
+            .checked_add(amount)
+            .ok_or(ProgramErrorCode::Overflow)?;
+        Ok(())
+    }
+
+    pub fn pending_tokens(&self) -> u64 {
+        self.accrued_tokens - self.withdrawn_tokens
+    }
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{constant::BASIS_POINTS, error::ProgramErrorCode};
+
+pub const FEE_SCHEDULE_SEED: &str = "fee_schedule";
+
+pub const MAX_CREATOR_FEE_OVERRIDES: usize = 16;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct FeePercentages {
+    pub creator_fee_percentage: u64,
+    pub service_fee_percentage: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CreatorFeeOverride {
+    pub creator: Pubkey,
+    pub creator_fee_percentage: u64,
+    pub service_fee_percentage: u64,
+}
+
+impl CreatorFeeOverride {
+    pub const LEN: usize = 32 + // creator (Pubkey)
+        8 + // creator_fee_percentage
+        8; // service_fee_percentage
+}
+
+/// Fees `draft_market` applies and the cap it validates them against.
+#[account]
+pub struct FeeSchedule {
+    pub bump: u8,
+    pub default_creator_fee_percentage: u64,
+    pub default_service_fee_percentage: u64,
+    /// Cap on `creator_fee_percentage + service_fee_percentage`, in basis points.
+    pub max_total_fee_percentage: u64,
+    pub overrides: Vec<CreatorFeeOverride>,
+}
+
+impl FeeSchedule {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        8 + // default_creator_fee_percentage
+        8 + // default_service_fee_percentage
+        8 + // max_total_fee_percentage
+        4 + MAX_CREATOR_FEE_OVERRIDES * CreatorFeeOverride::LEN; // overrides
+
+    /// Fees for `creator`: its override if it has one, the defaults otherwise.
+    pub fn fees_for(&self, creator: &Pubkey) -> (u64, u64) {
+        match self.overrides.iter().find(|o| o.creator == *creator) {
+            Some(o) => (o.creator_fee_percentage, o.service_fee_percentage),
+            None => (
+                self.default_creator_fee_percentage,
+                self.default_service_fee_percentage,
+            ),
+        }
+    }
+
+    pub fn validate(&self, creator_fee_percentage: u64, service_fee_percentage: u64) -> Result<()> {
+        let total_fee_percentage = creator_fee_percentage
+            .checked_add(service_fee_percentage)
+            .ok_or(ProgramErrorCode::InvalidFeePercentage)?;
+        require!(
+            total_fee_percentage <= self.max_total_fee_percentage
+                && total_fee_percentage <= BASIS_POINTS as u64,
+            ProgramErrorCode::InvalidFeePercentage
+        );
+        Ok(())
+    }
+}
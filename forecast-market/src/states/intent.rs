@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+pub const INTENT_NONCE_SEED: &str = "intent_nonce";
+pub const INTENT_ESCROW_SEED: &str = "intent_escrow";
+
+/// Domain separator prepended to every signed bet intent.
+pub const BET_INTENT_DOMAIN: &[u8] = b"predix:bet_intent";
+
+/// Off-chain bet a user signs so a relayer can submit it for them.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BetIntent {
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub amount: u64,
+    pub nonce: u64,
+    pub expiry: u64,
+}
+
+impl BetIntent {
+    /// Bytes the user signs: domain, program id, then the borsh encoded intent.
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let mut message = Vec::with_capacity(BET_INTENT_DOMAIN.len() + 32 + 40);
+        message.extend_from_slice(BET_INTENT_DOMAIN);
+        message.extend_from_slice(crate::ID.as_ref());
+        message.extend_from_slice(&self.try_to_vec()?);
+        Ok(message)
+    }
+}
+
+/// Per-voter intent state. It is also the authority of the voter's intent
+/// escrow, `[INTENT_ESCROW_SEED, voter, bet_mint]`, which relayed bets are
+/// paid from.
+#[account]
+pub struct IntentNonceAccount {
+    pub bump: u8,
+    pub voter: Pubkey,
+    pub next_nonce: u64,
+}
+
+impl IntentNonceAccount {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        32 + // voter (Pubkey)
+        8; // next_nonce
+}
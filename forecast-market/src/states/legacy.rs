@@ -0,0 +1,764 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    Answer, AnswerAccount, BettingAccount, BettingCrossChainAccount, ConfigAccount, MarketAccount,
+    MarketStatus, MarketType, RewardPolicy,
+};
+
+/// Layout version an account is stored in, 0 when it predates versioning.
+/// Versioned accounts start with their version right after the
+/// discriminator, where unversioned ones have their bump; a bump is never as
+/// low as a version, so the two can't be mistaken.
+pub fn stored_version(data: &[u8], discriminator: &[u8], current_version: u8) -> Result<u8> {
+    require!(
+        data.len() > discriminator.len() && data.starts_with(discriminator),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    let version = data[discriminator.len()];
+    Ok(if version <= current_version {
+        version
+    } else {
+        0
+    })
+}
+
+/// Decode an account in an older layout `T`, see `stored_version`.
+pub fn read_legacy_account<T: AnchorDeserialize>(data: &[u8], discriminator: &[u8]) -> Result<T> {
+    T::deserialize(&mut &data[discriminator.len()..])
+        .map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+/// `ConfigAccount` before accounts were versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ConfigAccountV0 {
+    pub bump: u8,
+    pub is_initialized: bool,
+    pub owner: Pubkey,
+    pub reward_mint: Pubkey,
+    pub reward_apr: u64,
+    pub service_fee_account: Pubkey,
+    pub remain_account: Pubkey,
+}
+
+impl From<ConfigAccountV0> for ConfigAccount {
+    fn from(legacy: ConfigAccountV0) -> Self {
+        ConfigAccount {
+            version: ConfigAccount::VERSION,
+            bump: legacy.bump,
+            is_initialized: legacy.is_initialized,
+            owner: legacy.owner,
+            reward_mint: legacy.reward_mint,
+            reward_apr: legacy.reward_apr,
+            service_fee_account: legacy.service_fee_account,
+            remain_account: legacy.remain_account,
+        }
+    }
+}
+
+/// `MarketAccount` before accounts were versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MarketAccountV0 {
+    pub bump: u8,
+    pub exist: bool,
+    pub creator: Pubkey,
+    pub bet_mint: Pubkey,
+    pub market_key: u64,
+    pub title: String,
+    pub status: MarketStatus,
+    pub creator_fee: u64,
+    pub creator_fee_percentage: u64,
+    pub service_fee_percentage: u64,
+    pub approve_time: u64,
+    pub finish_time: u64,
+    pub adjourn_time: u64,
+    pub success_time: u64,
+    pub market_total_tokens: u64,
+    pub market_remain_tokens: u64,
+    pub correct_answer_key: u64,
+    pub market_reward_base_tokens: u64,
+}
+
+impl MarketAccountV0 {
+    /// Unversioned markets are parimutuel and read the APR from the config
+    /// at claim time with no budget, so approved ones keep `reward_apr` and
+    /// an unlimited budget. Their service fee was already taken at success.
+    pub fn migrate(self, reward_apr: u64) -> MarketAccount {
+        let reward_apr = if self.status == MarketStatus::Draft {
+            0
+        } else {
+            reward_apr
+        };
+
+        MarketAccount {
+            version: MarketAccount::VERSION,
+            bump: self.bump,
+            exist: self.exist,
+            creator: self.creator,
+            bet_mint: self.bet_mint,
+            market_key: self.market_key,
+            title: self.title,
+            status: self.status,
+            creator_fee: self.creator_fee,
+            creator_fee_percentage: self.creator_fee_percentage,
+            service_fee_percentage: self.service_fee_percentage,
+            approve_time: self.approve_time,
+            finish_time: self.finish_time,
+            adjourn_time: self.adjourn_time,
+            success_time: self.success_time,
+            market_total_tokens: self.market_total_tokens,
+            market_remain_tokens: self.market_remain_tokens,
+            correct_answer_key: self.correct_answer_key,
+            market_reward_base_tokens: self.market_reward_base_tokens,
+            market_type: MarketType::Parimutuel,
+            outcome_collateral_tokens: 0,
+            house_tokens: 0,
+            reward_apr,
+            reward_budget: u64::MAX,
+            reward_paid: 0,
+            reward_stake_time: 0,
+            reward_policy: RewardPolicy::Linear,
+            reward_bet_count: 0,
+            vesting_threshold: 0,
+            vesting_period: 0,
+            creator_split: Pubkey::default(),
+            service_split: Pubkey::default(),
+            claim_service_fee_percentage: 0,
+            service_fee_accrued: 0,
+            resolver_committee: Pubkey::default(),
+            operator: Pubkey::default(),
+            vesting_bet_tokens: 0,
+            vesting_reward_tokens: 0,
+        }
+    }
+}
+
+/// `MarketAccount` before markets had an `operator`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MarketAccountV1 {
+    pub version: u8,
+    pub bump: u8,
+    pub exist: bool,
+    pub creator: Pubkey,
+    pub bet_mint: Pubkey,
+    pub market_key: u64,
+    pub title: String,
+    pub status: MarketStatus,
+    pub creator_fee: u64,
+    pub creator_fee_percentage: u64,
+    pub service_fee_percentage: u64,
+    pub approve_time: u64,
+    pub finish_time: u64,
+    pub adjourn_time: u64,
+    pub success_time: u64,
+    pub market_total_tokens: u64,
+    pub market_remain_tokens: u64,
+    pub correct_answer_key: u64,
+    pub market_reward_base_tokens: u64,
+    pub market_type: MarketType,
+    pub outcome_collateral_tokens: u64,
+    pub house_tokens: u64,
+    pub reward_apr: u64,
+    pub reward_budget: u64,
+    pub reward_paid: u64,
+    pub reward_stake_time: u128,
+    pub reward_policy: RewardPolicy,
+    pub reward_bet_count: u64,
+    pub vesting_threshold: u64,
+    pub vesting_period: u64,
+    pub creator_split: Pubkey,
+    pub service_split: Pubkey,
+    pub claim_service_fee_percentage: u64,
+    pub service_fee_accrued: u64,
+    pub resolver_committee: Pubkey,
+}
+
+impl From<MarketAccountV1> for MarketAccount {
+    fn from(legacy: MarketAccountV1) -> Self {
+        MarketAccount {
+            version: MarketAccount::VERSION,
+            bump: legacy.bump,
+            exist: legacy.exist,
+            creator: legacy.creator,
+            bet_mint: legacy.bet_mint,
+            market_key: legacy.market_key,
+            title: legacy.title,
+            status: legacy.status,
+            creator_fee: legacy.creator_fee,
+            creator_fee_percentage: legacy.creator_fee_percentage,
+            service_fee_percentage: legacy.service_fee_percentage,
+            approve_time: legacy.approve_time,
+            finish_time: legacy.finish_time,
+            adjourn_time: legacy.adjourn_time,
+            success_time: legacy.success_time,
+            market_total_tokens: legacy.market_total_tokens,
+            market_remain_tokens: legacy.market_remain_tokens,
+            correct_answer_key: legacy.correct_answer_key,
+            market_reward_base_tokens: legacy.market_reward_base_tokens,
+            market_type: legacy.market_type,
+            outcome_collateral_tokens: legacy.outcome_collateral_tokens,
+            house_tokens: legacy.house_tokens,
+            reward_apr: legacy.reward_apr,
+            reward_budget: legacy.reward_budget,
+            reward_paid: legacy.reward_paid,
+            reward_stake_time: legacy.reward_stake_time,
+            reward_policy: legacy.reward_policy,
+            reward_bet_count: legacy.reward_bet_count,
+            vesting_threshold: legacy.vesting_threshold,
+            vesting_period: legacy.vesting_period,
+            creator_split: legacy.creator_split,
+            service_split: legacy.service_split,
+            claim_service_fee_percentage: legacy.claim_service_fee_percentage,
+            service_fee_accrued: legacy.service_fee_accrued,
+            resolver_committee: legacy.resolver_committee,
+            operator: Pubkey::default(),
+            vesting_bet_tokens: 0,
+            vesting_reward_tokens: 0,
+        }
+    }
+}
+
+/// `MarketAccount` before markets tracked their vesting claims.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MarketAccountV2 {
+    pub version: u8,
+    pub bump: u8,
+    pub exist: bool,
+    pub creator: Pubkey,
+    pub bet_mint: Pubkey,
+    pub market_key: u64,
+    pub title: String,
+    pub status: MarketStatus,
+    pub creator_fee: u64,
+    pub creator_fee_percentage: u64,
+    pub service_fee_percentage: u64,
+    pub approve_time: u64,
+    pub finish_time: u64,
+    pub adjourn_time: u64,
+    pub success_time: u64,
+    pub market_total_tokens: u64,
+    pub market_remain_tokens: u64,
+    pub correct_answer_key: u64,
+    pub market_reward_base_tokens: u64,
+    pub market_type: MarketType,
+    pub outcome_collateral_tokens: u64,
+    pub house_tokens: u64,
+    pub reward_apr: u64,
+    pub reward_budget: u64,
+    pub reward_paid: u64,
+    pub reward_stake_time: u128,
+    pub reward_policy: RewardPolicy,
+    pub reward_bet_count: u64,
+    pub vesting_threshold: u64,
+    pub vesting_period: u64,
+    pub creator_split: Pubkey,
+    pub service_split: Pubkey,
+    pub claim_service_fee_percentage: u64,
+    pub service_fee_accrued: u64,
+    pub resolver_committee: Pubkey,
+    pub operator: Pubkey,
+}
+
+impl From<MarketAccountV2> for MarketAccount {
+    fn from(legacy: MarketAccountV2) -> Self {
+        MarketAccount {
+            version: MarketAccount::VERSION,
+            bump: legacy.bump,
+            exist: legacy.exist,
+            creator: legacy.creator,
+            bet_mint: legacy.bet_mint,
+            market_key: legacy.market_key,
+            title: legacy.title,
+            status: legacy.status,
+            creator_fee: legacy.creator_fee,
+            creator_fee_percentage: legacy.creator_fee_percentage,
+            service_fee_percentage: legacy.service_fee_percentage,
+            approve_time: legacy.approve_time,
+            finish_time: legacy.finish_time,
+            adjourn_time: legacy.adjourn_time,
+            success_time: legacy.success_time,
+            market_total_tokens: legacy.market_total_tokens,
+            market_remain_tokens: legacy.market_remain_tokens,
+            correct_answer_key: legacy.correct_answer_key,
+            market_reward_base_tokens: legacy.market_reward_base_tokens,
+            market_type: legacy.market_type,
+            outcome_collateral_tokens: legacy.outcome_collateral_tokens,
+            house_tokens: legacy.house_tokens,
+            reward_apr: legacy.reward_apr,
+            reward_budget: legacy.reward_budget,
+            reward_paid: legacy.reward_paid,
+            reward_stake_time: legacy.reward_stake_time,
+            reward_policy: legacy.reward_policy,
+            reward_bet_count: legacy.reward_bet_count,
+            vesting_threshold: legacy.vesting_threshold,
+            vesting_period: legacy.vesting_period,
+            creator_split: legacy.creator_split,
+            service_split: legacy.service_split,
+            claim_service_fee_percentage: legacy.claim_service_fee_percentage,
+            service_fee_accrued: legacy.service_fee_accrued,
+            resolver_committee: legacy.resolver_committee,
+            operator: legacy.operator,
+            vesting_bet_tokens: 0,
+            vesting_reward_tokens: 0,
+        }
+    }
+}
+
+/// `AnswerAccount` before accounts were versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AnswerAccountV0 {
+    pub bump: u8,
+    pub answers: Vec<Answer>,
+    pub exist: bool,
+}
+
+impl From<AnswerAccountV0> for AnswerAccount {
+    fn from(legacy: AnswerAccountV0) -> Self {
+        AnswerAccount {
+            version: AnswerAccount::VERSION,
+            bump: legacy.bump,
+            answers: legacy.answers,
+            exist: legacy.exist,
+            odds: Vec::new(),
+        }
+    }
+}
+
+/// `BettingAccount` before accounts were versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BettingAccountV0 {
+    pub bump: u8,
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub voter: Pubkey,
+    pub tokens: u64,
+    pub create_time: u64,
+    pub exist: bool,
+}
+
+impl From<BettingAccountV0> for BettingAccount {
+    fn from(legacy: BettingAccountV0) -> Self {
+        BettingAccount {
+            version: BettingAccount::VERSION,
+            bump: legacy.bump,
+            market_key: legacy.market_key,
+            answer_key: legacy.answer_key,
+            voter: legacy.voter,
+            tokens: legacy.tokens,
+            create_time: legacy.create_time,
+            exist: legacy.exist,
+            locked_payout: 0,
+        }
+    }
+}
+
+/// `BettingCrossChainAccount` before accounts were versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BettingCrossChainAccountV0 {
+    pub bump: u8,
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub create_time: u64,
+    pub exist: bool,
+    pub chain_id: u16,
+    pub voter_wallet_address: [u8; 32],
+    pub token_address: [u8; 32],
+    pub tokens: u64,
+}
+
+impl From<BettingCrossChainAccountV0> for BettingCrossChainAccount {
+    fn from(legacy: BettingCrossChainAccountV0) -> Self {
+        BettingCrossChainAccount {
+            version: BettingCrossChainAccount::VERSION,
+            bump: legacy.bump,
+            market_key: legacy.market_key,
+            answer_key: legacy.answer_key,
+            create_time: legacy.create_time,
+            exist: legacy.exist,
+            chain_id: legacy.chain_id,
+            voter_wallet_address: legacy.voter_wallet_address,
+            token_address: legacy.token_address,
+            tokens: legacy.tokens,
+            settled: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    /// Lay out `legacy` the way Anchor stored it: discriminator, then fields,
+    /// zero-padded to the space the account was created with.
+    fn legacy_data<T: AnchorSerialize>(discriminator: &[u8], legacy: &T, space: usize) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data.resize(space, 0);
+        data
+    }
+
+    /// Migrate `data`, stored in `from_version`, the way the `migrate_*`
+    /// instructions do and read it back in the current layout.
+    fn upgrade<L: AnchorDeserialize, T: AccountSerialize + AccountDeserialize + Discriminator>(
+        data: &[u8],
+        from_version: u8,
+        current_version: u8,
+        migrate: impl FnOnce(L) -> T,
+        space: usize,
+    ) -> T {
+        assert_eq!(
+            stored_version(data, &T::DISCRIMINATOR, current_version).unwrap(),
+            from_version
+        );
+
+        let legacy = read_legacy_account::<L>(data, &T::DISCRIMINATOR).unwrap();
+        let mut migrated = Vec::new();
+        migrate(legacy).try_serialize(&mut migrated).unwrap();
+        assert!(migrated.len() <= space);
+        migrated.resize(space, 0);
+
+        assert_eq!(
+            stored_version(&migrated, &T::DISCRIMINATOR, current_version).unwrap(),
+            current_version
+        );
+
+        T::try_deserialize(&mut migrated.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_migrate_market_account() {
+        let legacy = MarketAccountV0 {
+            bump: 254,
+            exist: true,
+            creator: Pubkey::new_unique(),
+            bet_mint: Pubkey::new_unique(),
+            market_key: 42,
+            title: "x".repeat(96),
+            status: MarketStatus::Approve,
+            creator_fee: 1,
+            creator_fee_percentage: 100,
+            service_fee_percentage: 200,
+            approve_time: 1_000,
+            finish_time: 0,
+            adjourn_time: 0,
+            success_time: 0,
+            market_total_tokens: 5_000,
+            market_remain_tokens: 0,
+            correct_answer_key: 0,
+            market_reward_base_tokens: 0,
+        };
+        let (creator, bet_mint) = (legacy.creator, legacy.bet_mint);
+        // Unversioned markets left no room for the title length prefix.
+        let data = legacy_data(&MarketAccount::DISCRIMINATOR, &legacy, 271);
+
+        let market = upgrade(
+            &data,
+            0,
+            MarketAccount::VERSION,
+            |legacy: MarketAccountV0| legacy.migrate(1_500),
+            MarketAccount::LEN,
+        );
+
+        assert_eq!(market.version, MarketAccount::VERSION);
+        assert_eq!(market.bump, 254);
+        assert_eq!(market.creator, creator);
+        assert_eq!(market.bet_mint, bet_mint);
+        assert_eq!(market.market_key, 42);
+        assert_eq!(market.title, "x".repeat(96));
+        assert!(market.status == MarketStatus::Approve);
+        assert_eq!(market.service_fee_percentage, 200);
+        assert_eq!(market.market_total_tokens, 5_000);
+        assert!(market.market_type == MarketType::Parimutuel);
+        assert_eq!(market.reward_apr, 1_500);
+        assert_eq!(market.reward_budget, u64::MAX);
+        assert_eq!(market.operator, Pubkey::default());
+    }
+
+    #[test]
+    fn test_migrate_draft_market_waits_for_approval_apr() {
+        let legacy = MarketAccountV0 {
+            bump: 255,
+            exist: true,
+            creator: Pubkey::default(),
+            bet_mint: Pubkey::default(),
+            market_key: 1,
+            title: String::new(),
+            status: MarketStatus::Draft,
+            creator_fee: 0,
+            creator_fee_percentage: 0,
+            service_fee_percentage: 0,
+            approve_time: 0,
+            finish_time: 0,
+            adjourn_time: 0,
+            success_time: 0,
+            market_total_tokens: 0,
+            market_remain_tokens: 0,
+            correct_answer_key: 0,
+            market_reward_base_tokens: 0,
+        };
+
+        assert_eq!(legacy.migrate(1_500).reward_apr, 0);
+    }
+
+    #[test]
+    fn test_migrate_market_account_v1_adds_operator() {
+        let legacy = MarketAccountV1 {
+            version: 1,
+            bump: 250,
+            exist: true,
+            creator: Pubkey::new_unique(),
+            bet_mint: Pubkey::new_unique(),
+            market_key: 7,
+            title: "x".repeat(100),
+            status: MarketStatus::Success,
+            creator_fee: 0,
+            creator_fee_percentage: 100,
+            service_fee_percentage: 200,
+            approve_time: 1_000,
+            finish_time: 2_000,
+            adjourn_time: 0,
+            success_time: 3_000,
+            market_total_tokens: 9_000,
+            market_remain_tokens: 8_000,
+            correct_answer_key: 3,
+            market_reward_base_tokens: 7_500,
+            market_type: MarketType::FixedOdds,
+            outcome_collateral_tokens: 0,
+            house_tokens: 400,
+            reward_apr: 1_000,
+            reward_budget: 50,
+            reward_paid: 20,
+            reward_stake_time: 12_345,
+            reward_policy: RewardPolicy::Participation { amount: 5 },
+            reward_bet_count: 4,
+            vesting_threshold: 6,
+            vesting_period: 60,
+            creator_split: Pubkey::new_unique(),
+            service_split: Pubkey::new_unique(),
+            claim_service_fee_percentage: 200,
+            service_fee_accrued: 30,
+            resolver_committee: Pubkey::new_unique(),
+        };
+        let resolver_committee = legacy.resolver_committee;
+        let data = legacy_data(
+            &MarketAccount::DISCRIMINATOR,
+            &legacy,
+            MarketAccount::LEN - 48,
+        );
+
+        let market = upgrade(
+            &data,
+            1,
+            MarketAccount::VERSION,
+            |legacy: MarketAccountV1| MarketAccount::from(legacy),
+            MarketAccount::LEN,
+        );
+
+        assert_eq!(market.version, MarketAccount::VERSION);
+        assert_eq!(market.title, "x".repeat(100));
+        assert!(market.market_type == MarketType::FixedOdds);
+        assert_eq!(market.house_tokens, 400);
+        assert_eq!(market.reward_stake_time, 12_345);
+        assert!(market.reward_policy == RewardPolicy::Participation { amount: 5 });
+        assert_eq!(market.service_fee_accrued, 30);
+        assert_eq!(market.resolver_committee, resolver_committee);
+        assert_eq!(market.operator, Pubkey::default());
+    }
+
+    #[test]
+    fn test_migrate_market_account_v2_tracks_vesting() {
+        let legacy = MarketAccountV2 {
+            version: 2,
+            bump: 249,
+            exist: true,
+            creator: Pubkey::new_unique(),
+            bet_mint: Pubkey::new_unique(),
+            market_key: 8,
+            title: "y".repeat(100),
+            status: MarketStatus::Approve,
+            creator_fee: 0,
+            creator_fee_percentage: 100,
+            service_fee_percentage: 200,
+            approve_time: 1_000,
+            finish_time: 2_000,
+            adjourn_time: 0,
+            success_time: 0,
+            market_total_tokens: 9_000,
+            market_remain_tokens: 9_000,
+            correct_answer_key: 0,
+            market_reward_base_tokens: 0,
+            market_type: MarketType::Parimutuel,
+            outcome_collateral_tokens: 0,
+            house_tokens: 0,
+            reward_apr: 1_000,
+            reward_budget: 50,
+            reward_paid: 0,
+            reward_stake_time: 12_345,
+            reward_policy: RewardPolicy::Linear,
+            reward_bet_count: 4,
+            vesting_threshold: 6,
+            vesting_period: 60,
+            creator_split: Pubkey::new_unique(),
+            service_split: Pubkey::new_unique(),
+            claim_service_fee_percentage: 0,
+            service_fee_accrued: 0,
+            resolver_committee: Pubkey::default(),
+            operator: Pubkey::new_unique(),
+        };
+        let operator = legacy.operator;
+        let data = legacy_data(
+            &MarketAccount::DISCRIMINATOR,
+            &legacy,
+            MarketAccount::LEN - 16,
+        );
+
+        let market = upgrade(
+            &data,
+            2,
+            MarketAccount::VERSION,
+            |legacy: MarketAccountV2| MarketAccount::from(legacy),
+            MarketAccount::LEN,
+        );
+
+        assert_eq!(market.version, MarketAccount::VERSION);
+        assert_eq!(market.title, "y".repeat(100));
+        assert_eq!(market.vesting_period, 60);
+        assert_eq!(market.operator, operator);
+        assert_eq!(market.vesting_bet_tokens, 0);
+        assert_eq!(market.vesting_reward_tokens, 0);
+    }
+
+    #[test]
+    fn test_migrate_config_account() {
+        let legacy = ConfigAccountV0 {
+            bump: 253,
+            is_initialized: true,
+            owner: Pubkey::new_unique(),
+            reward_mint: Pubkey::new_unique(),
+            reward_apr: 700,
+            service_fee_account: Pubkey::new_unique(),
+            remain_account: Pubkey::new_unique(),
+        };
+        let (owner, remain_account) = (legacy.owner, legacy.remain_account);
+        let data = legacy_data(
+            &ConfigAccount::DISCRIMINATOR,
+            &legacy,
+            ConfigAccount::LEN - 1,
+        );
+
+        let config = upgrade(
+            &data,
+            0,
+            ConfigAccount::VERSION,
+            |legacy: ConfigAccountV0| ConfigAccount::from(legacy),
+            ConfigAccount::LEN,
+        );
+
+        assert_eq!(config.version, ConfigAccount::VERSION);
+        assert_eq!(config.bump, 253);
+        assert_eq!(config.owner, owner);
+        assert_eq!(config.reward_apr, 700);
+        assert_eq!(config.remain_account, remain_account);
+    }
+
+    #[test]
+    fn test_migrate_answer_account() {
+        let legacy = AnswerAccountV0 {
+            bump: 252,
+            answers: vec![
+                Answer {
+                    answer_key: 1,
+                    answer_total_tokens: 300,
+                },
+                Answer {
+                    answer_key: 2,
+                    answer_total_tokens: 700,
+                },
+            ],
+            exist: true,
+        };
+        let data = legacy_data(&AnswerAccount::DISCRIMINATOR, &legacy, 271);
+
+        let answer = upgrade(
+            &data,
+            0,
+            AnswerAccount::VERSION,
+            |legacy: AnswerAccountV0| AnswerAccount::from(legacy),
+            271,
+        );
+
+        assert_eq!(answer.version, AnswerAccount::VERSION);
+        assert_eq!(answer.answers.len(), 2);
+        assert_eq!(answer.answers[1].answer_key, 2);
+        assert_eq!(answer.answers[1].answer_total_tokens, 700);
+        assert!(answer.exist);
+        assert!(answer.odds.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_betting_account() {
+        let legacy = BettingAccountV0 {
+            bump: 251,
+            market_key: 42,
+            answer_key: 2,
+            voter: Pubkey::new_unique(),
+            tokens: 1_000,
+            create_time: 1_700_000_000,
+            exist: true,
+        };
+        let voter = legacy.voter;
+        // `bet` used to create betting accounts with the space of a market.
+        let data = legacy_data(&BettingAccount::DISCRIMINATOR, &legacy, 271);
+
+        let betting = upgrade(
+            &data,
+            0,
+            BettingAccount::VERSION,
+            |legacy: BettingAccountV0| BettingAccount::from(legacy),
+            271,
+        );
+
+        assert_eq!(betting.version, BettingAccount::VERSION);
+        assert_eq!(betting.market_key, 42);
+        assert_eq!(betting.voter, voter);
+        assert_eq!(betting.tokens, 1_000);
+        assert_eq!(betting.create_time, 1_700_000_000);
+        assert_eq!(betting.locked_payout, 0);
+    }
+
+    #[test]
+    fn test_migrate_betting_cross_chain_account() {
+        let legacy = BettingCrossChainAccountV0 {
+            bump: 250,
+            market_key: 42,
+            answer_key: 2,
+            create_time: 1_700_000_000,
+            exist: true,
+            chain_id: 43114,
+            voter_wallet_address: [7; 32],
+            token_address: [9; 32],
+            tokens: 1_000,
+        };
+        let data = legacy_data(
+            &BettingCrossChainAccount::DISCRIMINATOR,
+            &legacy,
+            BettingCrossChainAccount::MAX_SIZE - 2,
+        );
+
+        let betting = upgrade(
+            &data,
+            0,
+            BettingCrossChainAccount::VERSION,
+            |legacy: BettingCrossChainAccountV0| BettingCrossChainAccount::from(legacy),
+            BettingCrossChainAccount::MAX_SIZE,
+        );
+
+        assert_eq!(betting.version, BettingCrossChainAccount::VERSION);
+        assert_eq!(betting.bump, 250);
+        assert_eq!(betting.chain_id, 43114);
+        assert_eq!(betting.voter_wallet_address, [7; 32]);
+        assert_eq!(betting.tokens, 1_000);
+        assert!(!betting.settled);
+    }
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+pub const LMSR_SEED: &str = "lmsr";
+
+/// Every LMSR trade evaluates the cost function over all outcomes, which has
+/// to fit in the compute budget.
+pub const MAX_LMSR_OUTCOMES: usize = 16;
+
+pub const SHARE_POSITION_SEED: &str = "share_position";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OutcomeShares {
+    pub answer_key: u64,
+    pub total_shares: u64,
+}
+
+#[account]
+pub struct LmsrAccount {
+    pub bump: u8,
+    pub market_key: u64,
+    pub liquidity_param: u64,
+    pub funding_tokens: u64,
+    pub outcomes: Vec<OutcomeShares>,
+}
+
+impl LmsrAccount {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        8 + // market_key
+        8 + // liquidity_param
+        8 + // funding_tokens
+        4 + (8 + 8) * MAX_LMSR_OUTCOMES; // outcomes
+
+    pub fn outcome_index(&self, answer_key: u64) -> Option<usize> {
+        self.outcomes
+            .iter()
+            .position(|outcome| outcome.answer_key == answer_key)
+    }
+
+    pub fn total_shares(&self) -> Vec<u64> {
+        self.outcomes
+            .iter()
+            .map(|outcome| outcome.total_shares)
+            .collect()
+    }
+}
+
+#[account]
+pub struct SharePositionAccount {
+    pub bump: u8,
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub voter: Pubkey,
+    pub shares: u64,
+    pub exist: bool,
+}
+
+impl SharePositionAccount {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        8 + // market_key
+        8 + // answer_key
+        32 + // voter (Pubkey)
+        8 + // shares
+        1; // exist (bool)
+}
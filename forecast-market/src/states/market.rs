@@ -1,19 +1,54 @@
 use anchor_lang::prelude::*;
 
+use crate::error::ProgramErrorCode;
+
 pub const MARKET_SEED: &str = "market";
+/// Reward escrow `[MARKET_REWARD_SEED, market_key]` holding a market's budget.
+pub const MARKET_REWARD_SEED: &str = "market_reward";
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
 pub enum MarketStatus {
+    #[default]
     Draft,
     Approve,
     Finished,
     Success,
     Adjourn,
+    /// Resolvers disagreed; waiting for a final ruling.
+    Disputed,
 }
 
-#[account]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarketType {
+    #[default]
+    Parimutuel,
+    Lmsr,
+    FixedOdds,
+}
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RewardPolicy {
+    /// APR on the tokens of losing bets for the time they were staked.
+    #[default]
+    Linear,
+    /// `Linear` boosted by up to `max_multiplier` basis points for bets placed
+    /// at approval, decaying to 1x for bets placed at finish.
+    EarlyBird { max_multiplier: u64 },
+    /// The same `amount` for every betting account.
+    Participation { amount: u64 },
+    /// `basis_points` of the tokens of a losing bet.
+    Consolation { basis_points: u64 },
+}
+
+impl RewardPolicy {
+    pub const MAX_SIZE: usize = 1 + // variant
+        8; // largest variant payload - u64
+}
+
+#[account]
+#[derive(Default)]
 pub struct MarketAccount {
+    pub version: u8,
     pub bump: u8,
     pub exist: bool,
     pub creator: Pubkey,
@@ -32,15 +67,40 @@ pub struct MarketAccount {
     pub market_remain_tokens: u64,
     pub correct_answer_key: u64,
     pub market_reward_base_tokens: u64,
+    pub market_type: MarketType,
+    pub outcome_collateral_tokens: u64,
+    pub house_tokens: u64,
+    pub reward_apr: u64,
+    pub reward_budget: u64,
+    pub reward_paid: u64,
+    pub reward_stake_time: u128,
+    pub reward_policy: RewardPolicy,
+    pub reward_bet_count: u64,
+    pub vesting_threshold: u64,
+    pub vesting_period: u64,
+    pub creator_split: Pubkey,
+    pub service_split: Pubkey,
+    pub claim_service_fee_percentage: u64,
+    pub service_fee_accrued: u64,
+    pub resolver_committee: Pubkey,
+    /// Key that may run this market alongside the protocol roles, or default.
+    pub operator: Pubkey,
+    /// Claimed bet tokens still vesting in the market vault.
+    pub vesting_bet_tokens: u64,
+    /// Claimed rewards still vesting in the market's reward escrow.
+    pub vesting_reward_tokens: u64,
 }
 
 impl MarketAccount {
+    pub const VERSION: u8 = 3;
+
     pub const LEN: usize = 8 + // discriminator
+        1 + // version
         1 + // bump
         32 + // creator
         32 + // bet mint
         8 + //market key
-        MAX_TITLE_LEN + //title 
+        4 + MAX_TITLE_LEN + //title - String
         1 + // status - MarketStatus (as u8)
         8 + // creator_fee - u64
         8 + // creator_fee_percentage - u64
@@ -53,8 +113,48 @@ impl MarketAccount {
         8 + // market_remain_tokens - u64
         8 + // correct_answer_key - u64
         8 + // market_reward_base_tokens - u64
+        1 + // market_type - MarketType (as u8)
+        8 + // outcome_collateral_tokens - u64
+        8 + // house_tokens - u64
+        8 + // reward_apr - u64
+        8 + // reward_budget - u64
+        8 + // reward_paid - u64
+        16 + // reward_stake_time - u128
+        RewardPolicy::MAX_SIZE + // reward_policy
+        8 + // reward_bet_count - u64
+        8 + // vesting_threshold - u64
+        8 + // vesting_period - u64
+        32 + // creator_split - Pubkey
+        32 + // service_split - Pubkey
+        8 + // claim_service_fee_percentage - u64
+        8 + // service_fee_accrued - u64
+        32 + // resolver_committee - Pubkey
+        32 + // operator - Pubkey
+        8 + // vesting_bet_tokens - u64
+        8 + // vesting_reward_tokens - u64
         1; // exist - bool
 
+    pub fn is_operator(&self, key: &Pubkey) -> bool {
+        self.operator != Pubkey::default() && self.operator == *key
+    }
+
+    /// Tokens in the market vault that belong to someone other than the
+    /// market's remainder: outcome token collateral, house liquidity,
+    /// accrued claim service fees and vesting bet tokens.
+    pub fn reserved_vault_tokens(&self) -> Result<u64> {
+        self.outcome_collateral_tokens
+            .checked_add(self.house_tokens)
+            .and_then(|result| result.checked_add(self.service_fee_accrued))
+            .and_then(|result| result.checked_add(self.vesting_bet_tokens))
+            .ok_or(error!(ProgramErrorCode::Overflow))
+    }
+
+    /// Markets approved with a reward budget hold it in their reward escrow.
+    /// Markets migrated from before budgets keep paying from the config
+    /// reward vault.
+    pub fn has_reward_escrow(&self) -> bool {
+        self.reward_budget != u64::MAX
+    }
 }
 
 pub const MAX_MARKET_KEY: usize = 100;
@@ -15,3 +15,54 @@ pub use foreign_emitter::*;
 
 pub mod answer;
 pub use answer::*;
+
+pub mod lmsr;
+pub use lmsr::*;
+
+pub mod outcome_token;
+pub use outcome_token::*;
+
+pub mod intent;
+pub use intent::*;
+
+pub mod reward_stake;
+pub use reward_stake::*;
+
+pub mod session;
+pub use session::*;
+
+pub mod vesting;
+pub use vesting::*;
+
+pub mod fee_schedule;
+pub use fee_schedule::*;
+
+pub mod fee_ledger;
+pub use fee_ledger::*;
+
+pub mod revenue_split;
+pub use revenue_split::*;
+
+pub mod fee_discount;
+pub use fee_discount::*;
+
+pub mod roles;
+pub use roles::*;
+
+pub mod owner_transfer;
+pub use owner_transfer::*;
+
+pub mod config_change;
+pub use config_change::*;
+
+pub mod resolution;
+pub use resolution::*;
+
+pub mod oracle;
+pub use oracle::*;
+
+pub mod resolution_vote;
+pub use resolution_vote::*;
+
+pub mod legacy;
+pub use legacy::*;
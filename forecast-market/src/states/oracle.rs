@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::{constant::BASIS_POINTS, error::ProgramErrorCode};
+
+pub const ORACLE_CONFIG_SEED: &str = "oracle_config";
+pub const RESOLUTION_PROPOSAL_SEED: &str = "resolution_proposal";
+pub const PROPOSAL_BOND_SEED: &str = "proposal_bond";
+
+/// Settings for optimistic resolution. Disputes are put to a reward token
+/// vote lasting `vote_period`; the `arbiter`, or the config owner without
+/// one, can still rule on them.
+#[account]
+pub struct OracleConfig {
+    pub bump: u8,
+    pub arbiter: Pubkey,
+    pub bond_basis_points: u16,
+    pub liveness: u64,
+    pub proposal_reward: u64,
+    pub vote_period: u64,
+}
+
+impl OracleConfig {
+    pub const LEN: usize = 8 + // discriminator
+        1 + // bump
+        32 + // arbiter (Pubkey)
+        2 + // bond_basis_points
+        8 + // liveness
+        8 + // proposal_reward
+        8; // vote_period
+
+    pub fn validate(bond_basis_points: u16, liveness: u64, vote_period: u64) -> Result<()> {
+        require!(
+            bond_basis_points > 0
+                && bond_basis_points <= BASIS_POINTS
+                && liveness > 0
+                && vote_period > 0,
+            ProgramErrorCode::InvalidOracleConfig
+        );
+        Ok(())
+    }
+
+    /// Bond posted by the proposer, and again by a disputer.
+    pub fn bond_amount(&self, market_total_tokens: u64) -> Result<u64> {
+        let bond_amount = (market_total_tokens as u128)
+            .checked_mul(self.bond_basis_points as u128)
+            .ok_or(ProgramErrorCode::MathOperationError)?
+            .checked_div(BASIS_POINTS as u128)
+            .ok_or(ProgramErrorCode::MathOperationError)?;
+
+        Ok(bond_amount as u64)
+    }
+
+    pub fn arbiter_or(&self, owner: &Pubkey) -> Pubkey {
+        if self.arbiter == Pubkey::default() {
+            *owner
+        } else {
+            self.arbiter
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalStatus {
+    Proposed,
+    Disputed,
+}
+
+/// Answer proposed for a finished market. It settles the market unless it is
+/// disputed before `liveness_end`.
+#[account]
+pub struct ResolutionProposal {
+    pub bump: u8,
+    pub market_key: u64,
+    pub proposer: Pubkey,
+    pub answer_key: u64,
+    pub bond_amount: u64,
+    pub liveness_end: u64,
+    pub status: ProposalStatus,
+    pub disputer: Pubkey,
+}
+
+impl ResolutionProposal {
+    pub const LEN: usize = 8 + // discriminator
+        1 + // bump
+        8 + // market_key
+        32 + // proposer (Pubkey)
+        8 + // answer_key
+        8 + // bond_amount
+        8 + // liveness_end
+        1 + // status
+        32; // disputer (Pubkey)
+
+    /// Side of the dispute the final answer agrees with.
+    pub fn winner(&self, correct_answer_key: u64) -> Pubkey {
+        if correct_answer_key == self.answer_key {
+            self.proposer
+        } else {
+            self.disputer
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ProgramErrorCode, MAX_ANWSER};
+
+pub const OUTCOME_MINT_SEED: &str = "outcome_mint";
+pub const OUTCOME_MINTS_SEED: &str = "outcome_mints";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OutcomeMintBump {
+    pub answer_key: u64,
+    pub bump: u8,
+}
+
+/// Bumps of a market's outcome mints, recorded by `create_outcome_mint` so
+/// complete sets can check every mint without a PDA search per answer. The
+/// market PDA is the mint authority of each of them.
+#[account]
+pub struct OutcomeMintsAccount {
+    pub bump: u8,
+    pub market_key: u64,
+    pub mints: Vec<OutcomeMintBump>,
+}
+
+impl OutcomeMintsAccount {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        8 + // market_key
+        4 + (8 + 1) * MAX_ANWSER; // mints
+
+    /// Address of the outcome token mint of `answer_key`.
+    pub fn mint_address(&self, answer_key: u64) -> Result<Pubkey> {
+        let mint = self
+            .mints
+            .iter()
+            .find(|mint| mint.answer_key == answer_key)
+            .ok_or(ProgramErrorCode::InvalidOutcomeAccounts)?;
+
+        Pubkey::create_program_address(
+            &[
+                OUTCOME_MINT_SEED.as_bytes(),
+                &self.market_key.to_le_bytes(),
+                &answer_key.to_le_bytes(),
+                &[mint.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| ProgramErrorCode::InvalidOutcomeAccounts.into())
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+pub const OWNER_TRANSFER_SEED: &str = "owner_transfer";
+
+/// Ownership transfer proposed by the current owner, waiting for
+/// `pending_owner` to accept it once `delay` seconds have passed.
+#[account]
+pub struct OwnerTransferAccount {
+    pub bump: u8,
+    pub pending_owner: Pubkey,
+    pub propose_time: u64,
+    pub delay: u64,
+}
+
+impl OwnerTransferAccount {
+    pub const LEN: usize = 8 + // discriminator
+        1 + // bump
+        32 + // pending_owner (Pubkey)
+        8 + // propose_time
+        8; // delay
+
+    pub fn executable_time(&self) -> u64 {
+        self.propose_time.saturating_add(self.delay)
+    }
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ProgramErrorCode;
+
+pub const RESOLVER_COMMITTEE_SEED: &str = "resolver_committee";
+pub const RESOLUTION_VOTES_SEED: &str = "resolution_votes";
+
+pub const MAX_COMMITTEE_MEMBERS: usize = 16;
+
+/// Resolvers a market can be attached to. `threshold` matching attestations
+/// resolve the market.
+#[account]
+pub struct ResolverCommittee {
+    pub bump: u8,
+    pub committee_key: u64,
+    pub threshold: u8,
+    pub members: Vec<Pubkey>,
+}
+
+impl ResolverCommittee {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        8 + // committee_key
+        1 + // threshold
+        4 + MAX_COMMITTEE_MEMBERS * 32; // members
+
+    pub fn validate(members: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !members.is_empty()
+                && members.len() <= MAX_COMMITTEE_MEMBERS
+                && threshold > 0
+                && threshold as usize <= members.len()
+                && members
+                    .iter()
+                    .enumerate()
+                    .all(|(index, member)| !members[..index].contains(member)),
+            ProgramErrorCode::InvalidCommittee
+        );
+        Ok(())
+    }
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ResolutionAttestation {
+    pub member: Pubkey,
+    pub answer_key: u64,
+}
+
+impl ResolutionAttestation {
+    pub const LEN: usize = 32 + // member (Pubkey)
+        8; // answer_key
+}
+
+#[account]
+pub struct ResolutionVotes {
+    pub bump: u8,
+    pub market_key: u64,
+    pub committee: Pubkey,
+    pub attestations: Vec<ResolutionAttestation>,
+    /// Set once `threshold` members attested the same answer.
+    pub agreed_answer_key: Option<u64>,
+}
+
+impl ResolutionVotes {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        8 + // market_key
+        32 + // committee (Pubkey)
+        4 + MAX_COMMITTEE_MEMBERS * ResolutionAttestation::LEN + // attestations
+        1 + 8; // agreed_answer_key - Option<u64>
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ProgramErrorCode, MAX_ANWSER};
+
+pub const DISPUTE_TALLY_SEED: &str = "dispute_tally";
+pub const RESOLUTION_VOTE_SEED: &str = "resolution_vote";
+pub const VOTE_LOCK_SEED: &str = "vote_lock";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct AnswerWeight {
+    pub answer_key: u64,
+    pub weight: u64,
+}
+
+impl AnswerWeight {
+    pub const LEN: usize = 8 + // answer_key
+        8; // weight
+}
+
+/// Reward tokens locked per answer while a disputed market is put to a
+/// vote. Once settled, voters of the winning answer share `slashed_bond`.
+#[account]
+pub struct DisputeTally {
+    pub bump: u8,
+    pub market_key: u64,
+    pub vote_end: u64,
+    pub weights: Vec<AnswerWeight>,
+    pub settled: bool,
+    pub winning_answer_key: u64,
+    pub winning_weight: u64,
+    pub slashed_bond: u64,
+}
+
+impl DisputeTally {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        8 + // market_key
+        8 + // vote_end
+        4 + MAX_ANWSER * AnswerWeight::LEN + // weights
+        1 + // settled
+        8 + // winning_answer_key
+        8 + // winning_weight
+        8; // slashed_bond
+
+    pub fn add_weight(&mut self, answer_key: u64, amount: u64) -> Result<()> {
+        match self
+            .weights
+            .iter_mut()
+            .find(|answer_weight| answer_weight.answer_key == answer_key)
+        {
+            Some(answer_weight) => {
+                answer_weight.weight = answer_weight
+                    .weight
+                    .checked_add(amount)
+                    .ok_or(ProgramErrorCode::Overflow)?;
+            }
+            None => self.weights.push(AnswerWeight {
+                answer_key,
+                weight: amount,
+            }),
+        }
+        Ok(())
+    }
+
+    /// Answer with the most locked weight, if one strictly leads.
+    pub fn leader(&self) -> Option<AnswerWeight> {
+        let leader = self
+            .weights
+            .iter()
+            .max_by_key(|answer_weight| answer_weight.weight)?;
+
+        let tied = self.weights.iter().any(|answer_weight| {
+            answer_weight.answer_key != leader.answer_key && answer_weight.weight == leader.weight
+        });
+
+        (leader.weight > 0 && !tied).then_some(*leader)
+    }
+
+    /// Share of `slashed_bond` owed to a winning voter who locked `amount`.
+    pub fn bond_share(&self, amount: u64) -> Result<u64> {
+        if self.winning_weight == 0 {
+            return Ok(0);
+        }
+
+        let share = (self.slashed_bond as u128)
+            .checked_mul(amount as u128)
+            .ok_or(ProgramErrorCode::MathOperationError)?
+            .checked_div(self.winning_weight as u128)
+            .ok_or(ProgramErrorCode::MathOperationError)?;
+
+        Ok(share as u64)
+    }
+}
+
+/// Reward tokens one voter locked on an answer of a disputed market.
+#[account]
+pub struct ResolutionVote {
+    pub bump: u8,
+    pub market_key: u64,
+    pub voter: Pubkey,
+    pub answer_key: u64,
+    pub amount: u64,
+}
+
+impl ResolutionVote {
+    pub const LEN: usize = 8 + // discriminator
+        1 + // bump
+        8 + // market_key
+        32 + // voter (Pubkey)
+        8 + // answer_key
+        8; // amount
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tally(weights: &[(u64, u64)]) -> DisputeTally {
+        let mut tally = DisputeTally {
+            bump: 0,
+            market_key: 1,
+            vote_end: 0,
+            weights: vec![],
+            settled: false,
+            winning_answer_key: 0,
+            winning_weight: 0,
+            slashed_bond: 0,
+        };
+        for (answer_key, amount) in weights {
+            tally.add_weight(*answer_key, *amount).unwrap();
+        }
+        tally
+    }
+
+    #[test]
+    fn test_leader_needs_strict_majority_weight() {
+        let leader = tally(&[(1, 300), (2, 500), (1, 100)]).leader().unwrap();
+        assert_eq!(leader.answer_key, 2);
+        assert_eq!(leader.weight, 500);
+
+        assert!(tally(&[(1, 300), (2, 300)]).leader().is_none());
+        assert!(tally(&[]).leader().is_none());
+    }
+
+    #[test]
+    fn test_bond_share_is_pro_rata() {
+        let mut tally = tally(&[(1, 400)]);
+        tally.winning_weight = 400;
+        tally.slashed_bond = 1_000;
+
+        assert_eq!(tally.bond_share(100).unwrap(), 250);
+        assert_eq!(tally.bond_share(300).unwrap(), 750);
+    }
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::{constant::BASIS_POINTS, error::ProgramErrorCode};
+
+pub const REVENUE_SPLIT_SEED: &str = "revenue_split";
+
+pub const MAX_SPLIT_RECIPIENTS: usize = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SplitRecipient {
+    pub recipient: Pubkey,
+    pub weight_bps: u16,
+}
+
+impl SplitRecipient {
+    pub const LEN: usize = 32 + // recipient (Pubkey)
+        2; // weight_bps
+}
+
+/// Weighted recipients a market's creator or service fee is shared between.
+#[account]
+pub struct RevenueSplit {
+    pub bump: u8,
+    pub split_key: u64,
+    pub recipients: Vec<SplitRecipient>,
+}
+
+impl RevenueSplit {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        8 + // split_key
+        4 + MAX_SPLIT_RECIPIENTS * SplitRecipient::LEN; // recipients
+
+    /// Weights must be positive, add up to `BASIS_POINTS` and name each
+    /// recipient once.
+    pub fn validate(recipients: &[SplitRecipient]) -> Result<()> {
+        require!(
+            !recipients.is_empty() && recipients.len() <= MAX_SPLIT_RECIPIENTS,
+            ProgramErrorCode::InvalidRevenueSplit
+        );
+
+        let mut total_weight: u64 = 0;
+        for (index, split_recipient) in recipients.iter().enumerate() {
+            require!(
+                split_recipient.weight_bps > 0
+                    && !recipients[..index]
+                        .iter()
+                        .any(|other| other.recipient == split_recipient.recipient),
+                ProgramErrorCode::InvalidRevenueSplit
+            );
+            total_weight += split_recipient.weight_bps as u64;
+        }
+
+        require!(
+            total_weight == BASIS_POINTS as u64,
+            ProgramErrorCode::InvalidRevenueSplit
+        );
+        Ok(())
+    }
+
+    /// Each recipient's part of `amount`. Rounding dust goes to the first one.
+    pub fn shares(&self, amount: u64) -> Vec<(Pubkey, u64)> {
+        let mut shares: Vec<(Pubkey, u64)> = self
+            .recipients
+            .iter()
+            .map(|split_recipient| {
+                let share = (amount as u128) * (split_recipient.weight_bps as u128)
+                    / (BASIS_POINTS as u128);
+                (split_recipient.recipient, share as u64)
+            })
+            .collect();
+
+        let distributed: u64 = shares.iter().map(|(_, share)| share).sum();
+        if let Some((_, first_share)) = shares.first_mut() {
+            *first_share += amount - distributed;
+        }
+
+        shares
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recipient(weight_bps: u16) -> SplitRecipient {
+        SplitRecipient {
+            recipient: Pubkey::new_unique(),
+            weight_bps,
+        }
+    }
+
+    #[test]
+    fn test_validate_weights() {
+        assert!(RevenueSplit::validate(&[recipient(7_000), recipient(3_000)]).is_ok());
+        assert!(RevenueSplit::validate(&[recipient(7_000), recipient(2_000)]).is_err());
+        assert!(RevenueSplit::validate(&[recipient(10_000), recipient(0)]).is_err());
+        assert!(RevenueSplit::validate(&[]).is_err());
+
+        let duplicate = recipient(5_000);
+        assert!(RevenueSplit::validate(&[duplicate, duplicate]).is_err());
+    }
+
+    #[test]
+    fn test_shares_follow_weights() {
+        let split = RevenueSplit {
+            bump: 0,
+            split_key: 1,
+            recipients: vec![recipient(5_000), recipient(3_333), recipient(1_667)],
+        };
+
+        let shares = split.shares(1_000);
+        assert_eq!(shares[0].1, 501);
+        assert_eq!(shares[1].1, 333);
+        assert_eq!(shares[2].1, 166);
+        assert_eq!(shares.iter().map(|(_, share)| share).sum::<u64>(), 1_000);
+    }
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+pub const REWARD_STAKE_SEED: &str = "reward_stake";
+pub const REWARD_STAKE_VAULT_SEED: &str = "reward_stake_vault";
+
+/// Reward tokens a wallet has locked up. It is also the authority of the
+/// stake vault, `[REWARD_STAKE_VAULT_SEED, owner]`. The staked amount sets
+/// the wallet's service fee discount, see `FeeDiscountTiers`.
+#[account]
+pub struct RewardStakeAccount {
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub unlock_time: u64,
+}
+
+impl RewardStakeAccount {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        32 + // owner (Pubkey)
+        8 + // amount
+        8; // unlock_time
+}
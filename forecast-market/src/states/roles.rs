@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+pub const ROLES_SEED: &str = "roles";
+
+pub const MAX_ROLE_GRANTS: usize = 32;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    /// Grants and revokes roles, changes the reward config.
+    Admin,
+    /// Drafts and approves markets and manages their answers.
+    MarketOperator,
+    /// Finishes, resolves and adjourns markets.
+    Resolver,
+    /// Retrieves tokens and manages fee accounts and house funds.
+    Treasurer,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct RoleGrant {
+    pub key: Pubkey,
+    pub role: Role,
+}
+
+impl RoleGrant {
+    pub const LEN: usize = 32 + // key (Pubkey)
+        1; // role
+}
+
+/// Keys allowed to call the admin instructions. `ConfigAccount.owner` holds
+/// every role without a grant.
+#[account]
+pub struct Roles {
+    pub bump: u8,
+    pub grants: Vec<RoleGrant>,
+}
+
+impl Roles {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        4 + MAX_ROLE_GRANTS * RoleGrant::LEN; // grants
+
+    pub fn has_role(&self, key: &Pubkey, role: Role, owner: &Pubkey) -> bool {
+        key == owner
+            || self
+                .grants
+                .iter()
+                .any(|grant| grant.key == *key && grant.role == role)
+    }
+}
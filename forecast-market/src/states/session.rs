@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ProgramErrorCode;
+
+pub const SESSION_SEED: &str = "session";
+
+pub const MAX_SESSION_MARKETS: usize = 16;
+
+/// Delegate key a wallet lets bet and claim on its behalf. The session PDA is
+/// the SPL delegate of the owner's bet token account, up to `spend_limit`.
+#[account]
+pub struct SessionAccount {
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub session_key: Pubkey,
+    pub spend_limit: u64,
+    pub spent: u64,
+    pub expiry: u64,
+    /// Market keys the session may touch. Empty means every market.
+    pub allowed_markets: Vec<u64>,
+}
+
+impl SessionAccount {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        32 + // owner (Pubkey)
+        32 + // session_key (Pubkey)
+        8 + // spend_limit
+        8 + // spent
+        8 + // expiry
+        4 + 8 * MAX_SESSION_MARKETS; // allowed_markets
+
+    pub fn authorize(&self, signer: &Pubkey, market_key: u64, now: u64) -> Result<()> {
+        require!(
+            self.session_key == *signer,
+            ProgramErrorCode::InvalidSession
+        );
+        require!(now <= self.expiry, ProgramErrorCode::SessionExpired);
+        require!(
+            self.allowed_markets.is_empty() || self.allowed_markets.contains(&market_key),
+            ProgramErrorCode::SessionScopeExceeded
+        );
+        Ok(())
+    }
+
+    pub fn authorize_spend(
+        &mut self,
+        signer: &Pubkey,
+        market_key: u64,
+        amount: u64,
+        now: u64,
+    ) -> Result<()> {
+        self.authorize(signer, market_key, now)?;
+
+        let spent = self
+            .spent
+            .checked_add(amount)
+            .ok_or(ProgramErrorCode::Overflow)?;
+        require!(
+            spent <= self.spend_limit,
+            ProgramErrorCode::SessionScopeExceeded
+        );
+        self.spent = spent;
+        Ok(())
+    }
+}
+
+/// Wallet an instruction acts for: the session owner when a session is used,
+/// the signer otherwise.
+pub fn session_owner(
+    session_account: &Option<Box<Account<SessionAccount>>>,
+    signer: &Pubkey,
+) -> Pubkey {
+    session_account
+        .as_ref()
+        .map_or(*signer, |session_account| session_account.owner)
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+pub const VESTING_SEED: &str = "vesting";
+
+/// Claim amounts held back for linear release between `start_time` and
+/// `end_time`. The tokens stay in the market vault and the reward vault; they
+/// are already taken out of `market_remain_tokens`.
+#[account]
+pub struct VestingAccount {
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub market_key: u64,
+    pub bet_amount: u64,
+    pub bet_withdrawn: u64,
+    pub reward_amount: u64,
+    pub reward_withdrawn: u64,
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+impl VestingAccount {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        1 + // bump
+        32 + // owner (Pubkey)
+        8 + // market_key
+        8 + // bet_amount
+        8 + // bet_withdrawn
+        8 + // reward_amount
+        8 + // reward_withdrawn
+        8 + // start_time
+        8; // end_time
+
+    fn vested_amount(&self, total_amount: u64, now: u64) -> u64 {
+        if now >= self.end_time {
+            total_amount
+        } else if now <= self.start_time {
+            0
+        } else {
+            ((total_amount as u128) * ((now - self.start_time) as u128)
+                / ((self.end_time - self.start_time) as u128)) as u64
+        }
+    }
+
+    pub fn withdrawable_bet_amount(&self, now: u64) -> u64 {
+        self.vested_amount(self.bet_amount, now) - self.bet_withdrawn
+    }
+
+    pub fn withdrawable_reward_amount(&self, now: u64) -> u64 {
+        self.vested_amount(self.reward_amount, now) - self.reward_withdrawn
+    }
+
+    pub fn is_fully_withdrawn(&self) -> bool {
+        self.bet_withdrawn == self.bet_amount && self.reward_withdrawn == self.reward_amount
+    }
+}